@@ -1,79 +1,26 @@
 //! Example: BotEyes real-time demonstration
 //!
-//! ```cargo run --example demo```
+//! ```cargo run --example demo --features demo-runtime```
 //!
 //! This opens a window and animates robot eyes in real-time.
-//! Press keys to change moods and modes.
+//! Press keys to change moods and modes (see `runtime::handle_key`).
 
-use boteyes::{Mood, RoboEyes};
-use minifb::{Key, KeyRepeat, Scale, Window, WindowOptions};
-use std::time::Duration;
+use boteyes::{Mood, RoboEyes, runtime};
 
 const WIDTH: usize = 256;
 const HEIGHT: usize = 128;
 
-fn main() {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut eyes = RoboEyes::new(WIDTH as u32, HEIGHT as u32);
     eyes.set_mood(Mood::Default);
+    // White eyes on a dark gray background
+    eyes.set_colors(0xFF, 0x44);
     eyes.open();
-    // idle mode: enabled, interval=2s, variation=4s, x_range=100%, y_range=100%
+    // idle mode: enabled, interval=2s, variation=4s, x_range=50%, y_range=50%
     eyes.set_idle_mode(true, 2, 4, 50, 50);
 
-    let mut buffer: Vec<u32> = vec![0; WIDTH * HEIGHT];
-
-    let mut window = Window::new(
-        "BotEyes Demo - Press keys: 1=Default, 2=Sad, 3=Angry, 4=Happy, 5=Confuse, 6=Surprise, 7=Loading, C=Cyclops, S=Sweat, SPACE=Blink, ESC=Quit",
-        WIDTH,
-        HEIGHT,
-        WindowOptions {
-            scale: Scale::X4,
-            ..WindowOptions::default()
-        },
-    )
-    .expect("Unable to open window");
-
-    let mut frame_count = 0;
-
-    while window.is_open() && !window.is_key_down(Key::Escape) {
-        // Handle keyboard input
-        for key in window.get_keys_pressed(KeyRepeat::No) {
-            match key {
-                Key::Key1 => eyes.set_mood(Mood::Default),
-                Key::Key2 => eyes.set_mood(Mood::Sad),
-                Key::Key3 => eyes.set_mood(Mood::Angry),
-                Key::Key4 => eyes.set_mood(Mood::Happy),
-                Key::Key5 => eyes.set_mood(Mood::Confuse),
-                Key::Key6 => eyes.set_mood(Mood::Surprise),
-                Key::Key7 => eyes.set_mood(Mood::Loading),
-                Key::C => eyes.set_cyclops(!eyes.is_cyclops()),
-                Key::S => eyes.set_sweat(!eyes.has_sweat()),
-                Key::Space => eyes.blink(),
-                _ => {}
-            }
-        }
-
-        let current_time = frame_count * 16; // ~60 FPS
-
-        // Draw frame and convert to buffer
-        let img = eyes.draw_eyes(current_time);
-        for (i, pixel) in img.pixels().enumerate() {
-            let gray = pixel.0[0];
-            let color = if gray > 128 {
-                0xFFFFFFFF // White
-            } else {
-                0xFF444444 // Dark gray background
-            };
-            buffer[i] = color;
-        }
-
-        // Update window
-        window
-            .update_with_buffer(&buffer, WIDTH, HEIGHT)
-            .expect("Failed to update buffer");
-
-        frame_count += 1;
-        std::thread::sleep(Duration::from_millis(16));
-    }
+    runtime::run_window(&mut eyes, WIDTH, HEIGHT, 4)?;
 
     println!("Demo closed.");
+    Ok(())
 }
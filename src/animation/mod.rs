@@ -4,12 +4,48 @@
 
 use rand::Rng;
 
+/// Default fall speed in pixels per millisecond
+///
+/// The original half a pixel per 16ms frame.
+pub const DEFAULT_SWEAT_SPEED: f32 = 0.5 / 16.0;
+
+/// Default fall acceleration in pixels per millisecond squared (30 px/s²)
+pub const DEFAULT_SWEAT_GRAVITY: f32 = 30.0 / 1_000_000.0;
+
+/// Vertical band of the screen sweat drops fall through
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweatRegion {
+    /// Y position drops spawn at
+    pub top: u32,
+    /// Maximum distance a drop falls
+    pub height: u32,
+}
+
+impl SweatRegion {
+    /// Default region scaled to the screen height
+    ///
+    /// Matches the original fixed 2px start and 10-20px fall on a 64px
+    /// screen.
+    pub fn for_screen(screen_height: u32) -> Self {
+        Self {
+            top: 2,
+            height: (screen_height * 18 / 64).max(1),
+        }
+    }
+}
+
 /// State for a single sweat drop animation
 ///
 /// Tracks position, size, and animation progress for
-/// one of the three sweat drops.
+/// one of the sweat drops.
 #[derive(Debug, Clone)]
 pub struct SweatDrop {
+    /// Band the drop falls through
+    region: SweatRegion,
+    /// Forehead slot this drop spawns in
+    slot: usize,
+    /// Total number of forehead slots
+    slots: usize,
     /// Initial X position (anchor point)
     x_initial: i32,
     /// Current X position
@@ -22,60 +58,66 @@ pub struct SweatDrop {
     width: f32,
     /// Current height
     height: f32,
-}
-
-/// Position for sweat drops on the forehead
-#[derive(Debug, Clone, Copy)]
-pub enum SweatPosition {
-    Left,
-    Center,
-    Right,
+    /// Speed gained from gravity since spawning, in pixels per millisecond
+    velocity: f32,
 }
 
 impl SweatDrop {
     /// Create a new sweat drop with random initial position
-    pub fn new(screen_width: u32, position: SweatPosition) -> Self {
+    ///
+    /// The forehead is split into `slots` equal-width zones across the
+    /// screen; the drop spawns at a random X inside zone `slot`, at the top
+    /// of `region`, and falls somewhere between half and all of its height.
+    pub fn new(screen_width: u32, region: SweatRegion, slot: usize, slots: usize) -> Self {
         let mut rng = rand::thread_rng();
-        let (x_initial, y_max) = match position {
-            SweatPosition::Left => (rng.gen_range(0..30), rng.gen_range(10..20)),
-            SweatPosition::Center => (
-                rng.gen_range(30..(screen_width as i32 - 30)),
-                rng.gen_range(10..20),
-            ),
-            SweatPosition::Right => (
-                (screen_width as i32 - 30) + rng.gen_range(0..30),
-                rng.gen_range(10..20),
-            ),
-        };
+        let slots = slots.max(1);
+        let zone = (screen_width as usize / slots).max(1) as i32;
+        let x_initial = slot as i32 * zone + rng.gen_range(0..zone);
+        let top = region.top as i32;
+        let y_max = top + rng.gen_range(region.height as i32 / 2..=region.height as i32);
 
         Self {
+            region,
+            slot,
+            slots,
             x_initial,
             x: x_initial as f32,
-            y: 2.0,
+            y: top as f32,
             y_max,
             width: 1.0,
             height: 2.0,
+            velocity: 0.0,
         }
     }
 
     /// Update sweat drop animation state
     ///
+    /// Moves the drop `elapsed_ms` worth of falling at `speed` pixels per
+    /// millisecond, plus whatever it has picked up from `gravity` (pixels
+    /// per millisecond squared) since it spawned.
+    ///
     /// Returns true if animation completed and needs reset.
-    pub fn update(&mut self) -> bool {
+    pub fn update(&mut self, elapsed_ms: f32, speed: f32, gravity: f32) -> bool {
+        let mut fallen = 0.0;
         let should_reset = if self.y as i32 <= self.y_max {
-            self.y += 0.5;
+            self.velocity += gravity * elapsed_ms;
+            fallen = (speed + self.velocity) * elapsed_ms;
+            self.y += fallen;
             false
         } else {
             true
         };
 
-        // Grow then shrink
-        if self.y as i32 <= self.y_max / 2 {
-            self.width += 0.5;
-            self.height += 0.5;
-        } else if self.y as i32 > self.y_max / 2 {
-            self.width -= 0.1;
-            self.height -= 0.5;
+        // Grow then shrink with the distance fallen; the deltas are per
+        // half pixel, the original fixed step
+        let steps = fallen / 0.5;
+        let halfway = (self.region.top as i32 + self.y_max) / 2;
+        if self.y as i32 <= halfway {
+            self.width += 0.5 * steps;
+            self.height += 0.5 * steps;
+        } else {
+            self.width -= 0.1 * steps;
+            self.height -= 0.5 * steps;
         }
 
         // Keep centered on initial X
@@ -94,34 +136,85 @@ impl SweatDrop {
         )
     }
 
-    /// Reset the sweat drop with new position
-    pub fn reset(&mut self, screen_width: u32, position: SweatPosition) {
-        *self = SweatDrop::new(screen_width, position);
+    /// Reset the sweat drop with a new position in the same slot
+    pub fn reset(&mut self, screen_width: u32) {
+        *self = SweatDrop::new(screen_width, self.region, self.slot, self.slots);
     }
 }
 
-/// Array of 3 sweat drops (left, center, right)
+/// Default number of sweat drops (left, center, right)
+pub const DEFAULT_SWEAT_COUNT: usize = 3;
+
+/// Sweat drops spread evenly across the forehead
 #[derive(Debug, Clone)]
-pub struct SweatDrops(pub [SweatDrop; 3]);
+pub struct SweatDrops(pub Vec<SweatDrop>);
 
 impl SweatDrops {
-    /// Create new sweat drops for given screen width
-    pub fn new(screen_width: u32) -> Self {
-        Self([
-            SweatDrop::new(screen_width, SweatPosition::Left),
-            SweatDrop::new(screen_width, SweatPosition::Center),
-            SweatDrop::new(screen_width, SweatPosition::Right),
-        ])
+    /// Create `count` sweat drops for given screen width and region
+    pub fn new(screen_width: u32, region: SweatRegion, count: usize) -> Self {
+        Self(
+            (0..count)
+                .map(|slot| SweatDrop::new(screen_width, region, slot, count))
+                .collect(),
+        )
     }
 
     /// Update all drops and return indices that need reset
-    pub fn update(&mut self) -> Vec<usize> {
+    ///
+    /// See [`SweatDrop::update()`] for the arguments.
+    pub fn update(&mut self, elapsed_ms: f32, speed: f32, gravity: f32) -> Vec<usize> {
         let mut reset_indices = Vec::new();
         for (i, drop) in self.0.iter_mut().enumerate() {
-            if drop.update() {
+            if drop.update(elapsed_ms, speed, gravity) {
                 reset_indices.push(i);
             }
         }
         reset_indices
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distinct_anchors() {
+        let drops = SweatDrops::new(128, SweatRegion::for_screen(64), 6);
+        let mut anchors: Vec<i32> = drops.0.iter().map(|d| d.x_initial).collect();
+        anchors.sort();
+        anchors.dedup();
+        assert_eq!(anchors.len(), 6);
+        assert!(anchors.iter().all(|x| (0..128).contains(x)));
+    }
+
+    #[test]
+    fn test_small_screen_region() {
+        let region = SweatRegion::for_screen(32);
+        let mut drops = SweatDrops::new(32, region, 3);
+        for _ in 0..200 {
+            for i in drops.update(16.0, DEFAULT_SWEAT_SPEED, DEFAULT_SWEAT_GRAVITY) {
+                drops.0[i].reset(32);
+            }
+            for drop in &drops.0 {
+                let (_, y, _, _) = drop.params();
+                assert!(y >= region.top as i32);
+                assert!(y <= (region.top + region.height) as i32 + 1);
+            }
+        }
+    }
+    #[test]
+    fn test_fall_speed() {
+        let updates_to_land = |mut drop: SweatDrop, speed: f32, gravity: f32| {
+            (1..).find(|_| drop.update(16.0, speed, gravity)).unwrap()
+        };
+        let drop = SweatDrop::new(128, SweatRegion::for_screen(64), 0, 1);
+
+        let default = updates_to_land(drop.clone(), DEFAULT_SWEAT_SPEED, 0.0);
+        let fast = updates_to_land(drop.clone(), DEFAULT_SWEAT_SPEED * 4.0, 0.0);
+        assert!(fast < default, "{fast} updates at 4x, {default} at 1x");
+
+        // Gravity speeds the drop up as it falls
+        let falling = updates_to_land(drop, DEFAULT_SWEAT_SPEED, DEFAULT_SWEAT_GRAVITY * 10.0);
+        assert!(falling < default);
+    }
+}
@@ -3,8 +3,33 @@
 //! Contains functions for drawing shapes on grayscale images:
 //! - Rounded rectangles
 //! - Filled triangles
+//! - Lines
+//! - Text in a tiny 3x5 font
+//! - Crossfades between whole frames
+//! - One-bit output through [`PixelSink`]
+//!
+//! These are the primitives the eyes are drawn with, and the supported
+//! low-level API for custom overlays on the same buffer. Shapes are
+//! clipped to the given screen size.
+//!
+//! ```
+//! use boteyes::{RoboEyes, draw_triangle};
+//!
+//! let mut eyes = RoboEyes::new(128, 64);
+//! let mut img = eyes.draw_eyes(0);
+//!
+//! // A small "nose" below the eyes
+//! draw_triangle(&mut img, 128, 64, 60, 54, 68, 54, 64, 62, 255);
+//! assert_eq!(img.get_pixel(64, 56)[0], 255);
+//! ```
+//!
+//! Internally a `Canvas` trait lets the same render code target a raster
+//! image, an anti-aliased raster, a one-bit [`PixelSink`] or SVG markup;
+//! the rasterizers write horizontal runs into any of these pixel stores.
+
+use std::fmt::Write;
 
-use image::GrayImage;
+use image::{GrayImage, RgbImage};
 
 /// Color for drawing (0 = black background, 255 = white foreground)
 type Color = u8;
@@ -41,60 +66,173 @@ pub fn draw_rounded_rect(
     radius: u32,
     color: Color,
 ) {
-    let radius = radius.min(width / 2).min(height / 2);
+    draw_rounded_rect_xy(
+        img,
+        screen_width,
+        screen_height,
+        x,
+        y,
+        width,
+        height,
+        radius,
+        radius,
+        color,
+    )
+}
 
-    for dy in 0..height as i32 {
-        for dx in 0..width as i32 {
-            let px = x + dx;
-            let py = y + dy;
+/// Draw a filled rectangle with elliptical corners
+///
+/// Same as [`draw_rounded_rect`] but with separate horizontal and vertical
+/// corner radii, used to compensate for non-square pixels.
+///
+/// # Notes
+///
+/// Each radius is limited to half of the matching dimension.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_rounded_rect_xy(
+    img: &mut GrayImage,
+    screen_width: u32,
+    screen_height: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    radius_x: u32,
+    radius_y: u32,
+    color: Color,
+) {
+    draw_rounded_rect_corners(
+        img,
+        screen_width,
+        screen_height,
+        x,
+        y,
+        width,
+        height,
+        [(radius_x, radius_y); 4],
+        color,
+    )
+}
 
-            // Skip out of bounds pixels
-            if px < 0 || px >= screen_width as i32 || py < 0 || py >= screen_height as i32 {
-                continue;
-            }
+/// Draw a filled rectangle with a different radius on each corner
+///
+/// `radii` holds the horizontal and vertical radius of the top-left,
+/// top-right, bottom-left and bottom-right corners, in that order.
+///
+/// # Notes
+///
+/// Each radius is limited to half of the matching dimension.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_rounded_rect_corners(
+    img: &mut GrayImage,
+    screen_width: u32,
+    screen_height: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    radii: [(u32, u32); 4],
+    color: Color,
+) {
+    fill_rounded_rect(
+        img,
+        screen_width,
+        screen_height,
+        x,
+        y,
+        width,
+        height,
+        radii,
+        color,
+    )
+}
 
-            // Check if point is inside the rounded corner
-            if is_in_rounded_corner(dx, dy, width, height, radius) {
-                continue;
-            }
+/// [`draw_rounded_rect_corners`] into any [`SpanTarget`]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fill_rounded_rect<T: SpanTarget + ?Sized>(
+    target: &mut T,
+    screen_width: u32,
+    screen_height: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    radii: [(u32, u32); 4],
+    color: Color,
+) {
+    let radii = radii.map(|(rx, ry)| (rx.min(width / 2), ry.min(height / 2)));
 
-            img.put_pixel(px as u32, py as u32, image::Luma([color]));
+    for dy in 0..height as i32 {
+        let py = y + dy;
+        if py < 0 || py >= screen_height as i32 {
+            continue;
         }
+
+        // Corners only cut into the ends of a row, so the inside is one run
+        let inside = |dx: &i32| !is_in_rounded_corner(*dx, dy, width, height, &radii);
+        let Some(first) = (0..width as i32).find(inside) else {
+            continue;
+        };
+        let last = (0..width as i32).rev().find(inside).unwrap_or(first);
+        clipped_span(target, screen_width, py, x + first, x + last + 1, color);
+    }
+}
+
+/// Write the on-screen part of pixels `x0..x1` of row `y`
+fn clipped_span<T: SpanTarget + ?Sized>(
+    target: &mut T,
+    screen_width: u32,
+    y: i32,
+    x0: i32,
+    x1: i32,
+    color: Color,
+) {
+    let (x0, x1) = (x0.max(0), x1.min(screen_width as i32));
+    if x0 < x1 {
+        target.span(y as u32, x0 as u32, x1 as u32, color);
     }
 }
 
 /// Check if a point is inside a rounded corner
-fn is_in_rounded_corner(dx: i32, dy: i32, width: u32, height: u32, radius: u32) -> bool {
-    let radius = radius as i32;
+///
+/// `radii` are the (already limited) top-left, top-right, bottom-left and
+/// bottom-right corner radii.
+fn is_in_rounded_corner(
+    dx: i32,
+    dy: i32,
+    width: u32,
+    height: u32,
+    radii: &[(u32, u32); 4],
+) -> bool {
+    let [tl, tr, bl, br] = radii.map(|(rx, ry)| (rx as i32, ry as i32));
     let width = width as i32;
     let height = height as i32;
 
+    // Outside the ellipse (cx/rx)^2 + (cy/ry)^2 = 1; the products are
+    // quartic in the radii, so widen to keep large corners exact
+    let outside = |cx: i32, cy: i32, (rx, ry): (i32, i32)| {
+        let [cx, cy, rx, ry] = [cx, cy, rx, ry].map(i128::from);
+        cx * cx * ry * ry + cy * cy * rx * rx > rx * rx * ry * ry
+    };
+
     // Top-left corner
-    if dx < radius && dy < radius {
-        let cx = radius - dx;
-        let cy = radius - dy;
-        return (cx * cx + cy * cy) > (radius * radius);
+    if dx < tl.0 && dy < tl.1 {
+        return outside(tl.0 - dx, tl.1 - dy, tl);
     }
 
     // Top-right corner
-    if dx >= width - radius && dy < radius {
-        let cx = dx - (width - radius);
-        let cy = radius - dy;
-        return (cx * cx + cy * cy) > (radius * radius);
+    if dx >= width - tr.0 && dy < tr.1 {
+        return outside(dx - (width - tr.0), tr.1 - dy, tr);
     }
 
     // Bottom-left corner
-    if dx < radius && dy >= height - radius {
-        let cx = radius - dx;
-        let cy = dy - (height - radius);
-        return (cx * cx + cy * cy) > (radius * radius);
+    if dx < bl.0 && dy >= height - bl.1 {
+        return outside(bl.0 - dx, dy - (height - bl.1), bl);
     }
 
     // Bottom-right corner
-    if dx >= width - radius && dy >= height - radius {
-        let cx = dx - (width - radius);
-        let cy = dy - (height - radius);
-        return (cx * cx + cy * cy) > (radius * radius);
+    if dx >= width - br.0 && dy >= height - br.1 {
+        return outside(dx - (width - br.0), dy - (height - br.1), br);
     }
 
     false
@@ -114,6 +252,12 @@ fn is_in_rounded_corner(dx: i32, dy: i32, width: u32, height: u32, radius: u32)
 /// * `x2, y2` - Second vertex
 /// * `x3, y3` - Third vertex
 /// * `color` - Fill color (0-255)
+///
+/// # Notes
+///
+/// Triangles thinner than a pixel (including collinear vertices) would
+/// miss every pixel centre, so their edges are drawn as 1px lines instead.
+/// Vertices may lie off-screen; pixels are clipped.
 #[allow(clippy::too_many_arguments)]
 pub fn draw_triangle(
     img: &mut GrayImage,
@@ -127,35 +271,932 @@ pub fn draw_triangle(
     y3: i32,
     color: Color,
 ) {
-    // Calculate bounding box
-    let min_x = x1.min(x2).min(x3).max(0);
-    let max_x = x1.max(x2).max(x3).min(screen_width as i32 - 1);
-    let min_y = y1.min(y2).min(y3).max(0);
-    let max_y = y1.max(y2).max(y3).min(screen_height as i32 - 1);
+    fill_triangle(
+        img,
+        screen_width,
+        screen_height,
+        x1,
+        y1,
+        x2,
+        y2,
+        x3,
+        y3,
+        color,
+    )
+}
 
+/// [`draw_triangle`] into any [`SpanTarget`]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fill_triangle<T: SpanTarget + ?Sized>(
+    target: &mut T,
+    screen_width: u32,
+    screen_height: u32,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    x3: i32,
+    y3: i32,
+    color: Color,
+) {
     // Edge vectors from vertex 1
     let edge1_x = x2 - x1;
     let edge1_y = y2 - y1;
     let edge2_x = x3 - x1;
     let edge2_y = y3 - y1;
+    let det = edge1_x * edge2_y - edge2_x * edge1_y;
+
+    // Less than 1px tall across its longest side: draw the outline
+    let longest = [(x1, y1, x2, y2), (x2, y2, x3, y3), (x3, y3, x1, y1)]
+        .iter()
+        .map(|&(ax, ay, bx, by)| (((bx - ax).pow(2) + (by - ay).pow(2)) as f32).sqrt())
+        .fold(0.0, f32::max);
+    if (det.abs() as f32) < longest || det == 0 {
+        for (ax, ay, bx, by) in [(x1, y1, x2, y2), (x2, y2, x3, y3), (x3, y3, x1, y1)] {
+            stroke_line(
+                target,
+                screen_width,
+                screen_height,
+                ax,
+                ay,
+                bx,
+                by,
+                1,
+                color,
+            );
+        }
+        return;
+    }
+
+    // Calculate bounding box
+    let min_x = x1.min(x2).min(x3).max(0);
+    let max_x = x1.max(x2).max(x3).min(screen_width as i32 - 1);
+    let min_y = y1.min(y2).min(y3).max(0);
+    let max_y = y1.max(y2).max(y3).min(screen_height as i32 - 1);
 
     for y in min_y..=max_y {
-        for x in min_x..=max_x {
-            // Barycentric coordinates
+        // Barycentric coordinates; a triangle row is a single run
+        let inside = |x: &i32| {
             let px = x - x1;
             let py = y - y1;
 
-            let det = edge1_x * edge2_y - edge2_x * edge1_y;
-            if det == 0 {
-                continue;
-            }
-
             let u = (px * edge2_y - py * edge2_x) as f32 / det as f32;
             let v = (edge1_x * py - edge1_y * px) as f32 / det as f32;
 
-            if u >= 0.0 && v >= 0.0 && u + v <= 1.0 {
-                img.put_pixel(x as u32, y as u32, image::Luma([color]));
+            u >= 0.0 && v >= 0.0 && u + v <= 1.0
+        };
+        let Some(first) = (min_x..=max_x).find(inside) else {
+            continue;
+        };
+        let last = (min_x..=max_x).rev().find(inside).unwrap_or(first);
+        clipped_span(target, screen_width, y, first, last + 1, color);
+    }
+}
+
+/// Draw a straight line
+///
+/// Uses Bresenham's algorithm, stamping a square brush of `thickness`
+/// pixels centred on each point of the line.
+///
+/// # Arguments
+///
+/// * `img` - Image buffer to draw on
+/// * `screen_width` - Width of the display
+/// * `screen_height` - Height of the display
+/// * `x0, y0` - Start point
+/// * `x1, y1` - End point
+/// * `thickness` - Line thickness in pixels (0 is treated as 1)
+/// * `color` - Line color (0-255)
+///
+/// # Notes
+///
+/// Pixels outside the screen are clipped, so lines may start or end
+/// off-screen.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_line(
+    img: &mut GrayImage,
+    screen_width: u32,
+    screen_height: u32,
+    x0: i32,
+    y0: i32,
+    x1: i32,
+    y1: i32,
+    thickness: u32,
+    color: Color,
+) {
+    stroke_line(
+        img,
+        screen_width,
+        screen_height,
+        x0,
+        y0,
+        x1,
+        y1,
+        thickness,
+        color,
+    )
+}
+
+/// [`draw_line`] into any [`SpanTarget`]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn stroke_line<T: SpanTarget + ?Sized>(
+    target: &mut T,
+    screen_width: u32,
+    screen_height: u32,
+    x0: i32,
+    y0: i32,
+    x1: i32,
+    y1: i32,
+    thickness: u32,
+    color: Color,
+) {
+    let thickness = thickness.max(1) as i32;
+    let lo = -(thickness - 1) / 2;
+    let hi = thickness / 2;
+
+    // Nothing to do if the line's bounding box misses the screen entirely
+    if x0.max(x1) + hi < 0
+        || y0.max(y1) + hi < 0
+        || x0.min(x1) + lo >= screen_width as i32
+        || y0.min(y1) + lo >= screen_height as i32
+    {
+        return;
+    }
+
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+    let (mut x, mut y) = (x0, y0);
+
+    loop {
+        for py in (y + lo..=y + hi).filter(|&py| py >= 0 && py < screen_height as i32) {
+            clipped_span(target, screen_width, py, x + lo, x + hi + 1, color);
+        }
+
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+/// Width and height of a [`draw_text`] character, in pixels
+pub const GLYPH_SIZE: (u32, u32) = (3, 5);
+
+/// Horizontal distance from one character to the next
+const GLYPH_ADVANCE: i32 = 4;
+
+/// Rows of a 3x5 glyph, bit 2 being the leftmost pixel
+///
+/// Covers A-Z (lowercase is drawn as uppercase), 0-9 and `! ? . - :`;
+/// anything else is blank.
+fn glyph(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b110, 0b001, 0b010, 0b100, 0b111],
+        '3' => [0b110, 0b001, 0b010, 0b001, 0b110],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b110, 0b001, 0b110],
+        '6' => [0b011, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b110],
+        '!' => [0b010, 0b010, 0b010, 0b000, 0b010],
+        '?' => [0b110, 0b001, 0b010, 0b000, 0b010],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        _ => [0; 5],
+    }
+}
+
+/// Width of `text` in pixels when drawn with [`draw_text`]
+pub fn text_width(text: &str) -> u32 {
+    (text.chars().count() as u32 * GLYPH_ADVANCE as u32).saturating_sub(1)
+}
+
+/// Lit runs `(y, x0, x1)` of `text` with its top-left corner at (`x`, `y`)
+fn text_runs(text: &str, x: i32, y: i32) -> impl Iterator<Item = (i32, i32, i32)> + '_ {
+    text.chars().enumerate().flat_map(move |(i, c)| {
+        let left = x + i as i32 * GLYPH_ADVANCE;
+        glyph(c)
+            .into_iter()
+            .enumerate()
+            .flat_map(move |(row, bits)| {
+                let lit = move |col: i32| (0..3).contains(&col) && bits & (0b100 >> col) != 0;
+                (0..3)
+                    .filter(move |&col| lit(col) && !lit(col - 1))
+                    .map(move |col| {
+                        let end = (col..3).find(|&c| !lit(c)).unwrap_or(3);
+                        (y + row as i32, left + col, left + end)
+                    })
+            })
+    })
+}
+
+/// Draw text in a tiny built-in 3x5 font
+///
+/// Characters are 3 pixels wide with 1 pixel between them. Supports
+/// A-Z (lowercase is drawn as uppercase), 0-9 and `! ? . - :`; other
+/// characters are left blank.
+///
+/// # Arguments
+///
+/// * `img` - Image buffer to draw on
+/// * `screen_width` - Width of the display
+/// * `screen_height` - Height of the display
+/// * `x, y` - Top-left corner of the first character
+/// * `text` - Text to draw
+/// * `color` - Text color (0-255)
+#[allow(clippy::too_many_arguments)]
+pub fn draw_text(
+    img: &mut GrayImage,
+    screen_width: u32,
+    screen_height: u32,
+    x: i32,
+    y: i32,
+    text: &str,
+    color: Color,
+) {
+    Raster::with_size(img, screen_width, screen_height).text(x, y, text, color);
+}
+
+/// Blend two same-sized frames
+///
+/// Dissolves between two renders, e.g. of two `RoboEyes` instances with
+/// different personalities, without tweening every setting.
+///
+/// # Arguments
+///
+/// * `a` - Frame shown at `t = 0`
+/// * `b` - Frame shown at `t = 1`
+/// * `t` - Blend factor, clamped to 0.0-1.0
+///
+/// # Panics
+///
+/// Panics if the frames differ in size.
+pub fn crossfade(a: &GrayImage, b: &GrayImage, t: f32) -> GrayImage {
+    assert_eq!(
+        a.dimensions(),
+        b.dimensions(),
+        "crossfaded frames must be the same size"
+    );
+    let t = t.clamp(0.0, 1.0);
+    let mut out = a.clone();
+    for (pixel, other) in out.pixels_mut().zip(b.pixels()) {
+        let blended = pixel[0] as f32 + (other[0] as f32 - pixel[0] as f32) * t;
+        pixel[0] = blended.round() as u8;
+    }
+    out
+}
+
+/// One-bit output target for [`RoboEyes::draw_into_sink()`](crate::RoboEyes::draw_into_sink)
+///
+/// Lets displays without a grayscale framebuffer (monochrome OLEDs, LED
+/// matrices, e-paper) take frames directly. Pixels outside the sink are
+/// ignored.
+pub trait PixelSink {
+    /// Set one pixel on or off
+    fn set_pixel(&mut self, x: u32, y: u32, lit: bool);
+
+    /// Set pixels `x0..x1` of row `y`
+    ///
+    /// The default calls [`set_pixel()`](Self::set_pixel) for each pixel;
+    /// override it when a run can be written faster.
+    fn fill_span(&mut self, y: u32, x0: u32, x1: u32, lit: bool) {
+        for x in x0..x1 {
+            self.set_pixel(x, y, lit);
+        }
+    }
+
+    /// Turn every pixel off
+    fn clear(&mut self);
+}
+
+/// Lit pixels are 255, unlit 0
+impl PixelSink for GrayImage {
+    fn set_pixel(&mut self, x: u32, y: u32, lit: bool) {
+        if x < self.width() && y < self.height() {
+            self.put_pixel(x, y, image::Luma([if lit { 255 } else { 0 }]));
+        }
+    }
+
+    fn clear(&mut self) {
+        self.fill(0);
+    }
+}
+
+/// Lit pixels are white, unlit black
+impl PixelSink for RgbImage {
+    fn set_pixel(&mut self, x: u32, y: u32, lit: bool) {
+        if x < self.width() && y < self.height() {
+            let level = if lit { 255 } else { 0 };
+            self.put_pixel(x, y, image::Rgb([level; 3]));
+        }
+    }
+
+    fn clear(&mut self) {
+        self.fill(0);
+    }
+}
+
+/// [`PixelSink`] over a raw byte buffer, one byte per pixel (0 or 255)
+///
+/// ```
+/// use boteyes::{RawBuffer, RoboEyes};
+///
+/// let mut eyes = RoboEyes::new(128, 64);
+/// let mut bytes = vec![0; 128 * 64];
+/// eyes.draw_into_sink(&mut RawBuffer::new(&mut bytes, 128), 0);
+/// ```
+pub struct RawBuffer<'a> {
+    buf: &'a mut [u8],
+    width: u32,
+}
+
+impl<'a> RawBuffer<'a> {
+    /// Wrap `buf` as rows of `width` bytes; a partial last row is ignored
+    pub fn new(buf: &'a mut [u8], width: u32) -> Self {
+        Self { buf, width }
+    }
+
+    fn row(&mut self, y: u32) -> Option<&mut [u8]> {
+        let width = self.width as usize;
+        let start = (y as usize).checked_mul(width)?;
+        self.buf.get_mut(start..start.checked_add(width)?)
+    }
+}
+
+impl PixelSink for RawBuffer<'_> {
+    fn set_pixel(&mut self, x: u32, y: u32, lit: bool) {
+        self.fill_span(y, x, x.saturating_add(1), lit);
+    }
+
+    fn fill_span(&mut self, y: u32, x0: u32, x1: u32, lit: bool) {
+        let width = self.width;
+        if let Some(row) = self.row(y) {
+            let (x0, x1) = (x0.min(width) as usize, x1.min(width) as usize);
+            if x0 < x1 {
+                row[x0..x1].fill(if lit { 255 } else { 0 });
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.buf.fill(0);
+    }
+}
+
+/// [`PixelSink`] over a packed MSB-first 1-bit buffer with `stride` bytes per row
+pub(crate) struct Packed<'a> {
+    pub buf: &'a mut [u8],
+    pub stride: usize,
+}
+
+impl PixelSink for Packed<'_> {
+    fn set_pixel(&mut self, x: u32, y: u32, lit: bool) {
+        let index = y as usize * self.stride + x as usize / 8;
+        if x as usize / 8 < self.stride
+            && let Some(byte) = self.buf.get_mut(index)
+        {
+            let bit = 0x80 >> (x % 8);
+            if lit {
+                *byte |= bit;
+            } else {
+                *byte &= !bit;
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.buf.fill(0);
+    }
+}
+
+/// Pixel store the rasterizers write into, one horizontal run at a time
+///
+/// Runs are already clipped to the screen.
+pub(crate) trait SpanTarget {
+    /// Set pixels `x0..x1` of row `y` to `color`
+    fn span(&mut self, y: u32, x0: u32, x1: u32, color: Color);
+}
+
+impl SpanTarget for GrayImage {
+    fn span(&mut self, y: u32, x0: u32, x1: u32, color: Color) {
+        let (width, height) = self.dimensions();
+        if y < height && x0 < x1.min(width) {
+            let row = (y * width) as usize;
+            let pixels: &mut [u8] = self;
+            pixels[row + x0 as usize..row + x1.min(width) as usize].fill(color);
+        }
+    }
+}
+
+/// [`SpanTarget`] lighting a [`PixelSink`] wherever the color reaches `threshold`
+pub(crate) struct Threshold<'a, S: ?Sized> {
+    pub sink: &'a mut S,
+    pub threshold: u8,
+}
+
+impl<S: PixelSink + ?Sized> SpanTarget for Threshold<'_, S> {
+    fn span(&mut self, y: u32, x0: u32, x1: u32, color: Color) {
+        self.sink.fill_span(y, x0, x1, color >= self.threshold);
+    }
+}
+
+/// Drawing target for the eye renderer
+///
+/// Coordinates are in screen pixels; shapes are clipped to the screen.
+pub(crate) trait Canvas {
+    /// Fill the whole canvas
+    fn fill(&mut self, color: Color);
+
+    /// Screen pixels written by shapes so far (0 for targets that don't
+    /// rasterize)
+    #[cfg(feature = "stats")]
+    fn pixels_written(&self) -> u64 {
+        0
+    }
+
+    /// Filled rectangle with per-corner elliptical radii
+    ///
+    /// `radii` are the top-left, top-right, bottom-left and bottom-right
+    /// `(horizontal, vertical)` radii.
+    fn rounded_rect_corners(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radii: [(u32, u32); 4],
+        color: Color,
+    );
+
+    /// Filled rectangle with elliptical corners
+    #[allow(clippy::too_many_arguments)]
+    fn rounded_rect_xy(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radius_x: u32,
+        radius_y: u32,
+        color: Color,
+    ) {
+        self.rounded_rect_corners(x, y, width, height, [(radius_x, radius_y); 4], color)
+    }
+
+    /// Filled rectangle with circular corners
+    fn rounded_rect(&mut self, x: i32, y: i32, width: u32, height: u32, radius: u32, color: Color) {
+        self.rounded_rect_xy(x, y, width, height, radius, radius, color)
+    }
+
+    /// Filled triangle
+    #[allow(clippy::too_many_arguments)]
+    fn triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32, color: Color);
+
+    /// Straight line of the given thickness
+    #[allow(clippy::too_many_arguments)]
+    fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, thickness: u32, color: Color);
+
+    /// Text in the built-in 3x5 font, top-left corner at (`x`, `y`)
+    fn text(&mut self, x: i32, y: i32, text: &str, color: Color);
+}
+
+/// [`Canvas`] rasterizing into a screen-sized [`SpanTarget`]
+pub(crate) struct Raster<'a, T: ?Sized> {
+    target: &'a mut T,
+    width: u32,
+    height: u32,
+    #[cfg(feature = "stats")]
+    written: u64,
+}
+
+impl<'a> Raster<'a, GrayImage> {
+    /// Raster over a whole grayscale image
+    pub fn new(img: &'a mut GrayImage) -> Self {
+        let (width, height) = img.dimensions();
+        Self::with_size(img, width, height)
+    }
+}
+
+impl<'a, T: SpanTarget + ?Sized> Raster<'a, T> {
+    /// Raster over a `width` x `height` screen
+    pub fn with_size(target: &'a mut T, width: u32, height: u32) -> Self {
+        Self {
+            target,
+            width,
+            height,
+            #[cfg(feature = "stats")]
+            written: 0,
+        }
+    }
+}
+
+/// Shapes reach the target through the raster so runs can be tallied
+impl<T: SpanTarget + ?Sized> SpanTarget for Raster<'_, T> {
+    fn span(&mut self, y: u32, x0: u32, x1: u32, color: Color) {
+        self.target.span(y, x0, x1, color);
+        #[cfg(feature = "stats")]
+        {
+            self.written += u64::from(x1 - x0);
+        }
+    }
+}
+
+impl<T: SpanTarget + ?Sized> Canvas for Raster<'_, T> {
+    fn fill(&mut self, color: Color) {
+        for y in 0..self.height {
+            self.target.span(y, 0, self.width, color);
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    fn pixels_written(&self) -> u64 {
+        self.written
+    }
+
+    fn rounded_rect_corners(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radii: [(u32, u32); 4],
+        color: Color,
+    ) {
+        let (sw, sh) = (self.width, self.height);
+        fill_rounded_rect(self, sw, sh, x, y, width, height, radii, color)
+    }
+
+    fn triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32, color: Color) {
+        let (sw, sh) = (self.width, self.height);
+        fill_triangle(self, sw, sh, x1, y1, x2, y2, x3, y3, color)
+    }
+
+    fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, thickness: u32, color: Color) {
+        let (sw, sh) = (self.width, self.height);
+        stroke_line(self, sw, sh, x0, y0, x1, y1, thickness, color)
+    }
+
+    fn text(&mut self, x: i32, y: i32, text: &str, color: Color) {
+        let (sw, sh) = (self.width, self.height);
+        for (py, x0, x1) in text_runs(text, x, y) {
+            if py >= 0 && py < sh as i32 {
+                clipped_span(self, sw, py, x0, x1, color);
+            }
+        }
+    }
+}
+
+/// [`Canvas`] drawing at a multiple of the screen resolution
+///
+/// [`resolve()`](Self::resolve) averages each block of samples down to one
+/// screen pixel, giving anti-aliased edges.
+pub(crate) struct Supersampled {
+    img: GrayImage,
+    factor: u32,
+    #[cfg(feature = "stats")]
+    samples: u64,
+}
+
+impl Supersampled {
+    pub fn new(width: u32, height: u32, factor: u32) -> Self {
+        let factor = factor.max(1);
+        Self {
+            img: GrayImage::new(width * factor, height * factor),
+            factor,
+            #[cfg(feature = "stats")]
+            samples: 0,
+        }
+    }
+
+    /// Average the samples into `out` (screen-sized)
+    pub fn resolve(&self, out: &mut GrayImage) {
+        let f = self.factor;
+        let samples = f * f;
+        for (x, y, pixel) in out.enumerate_pixels_mut() {
+            let mut sum = 0;
+            for sy in 0..f {
+                for sx in 0..f {
+                    sum += self.img.get_pixel(x * f + sx, y * f + sy)[0] as u32;
+                }
+            }
+            pixel[0] = ((sum + samples / 2) / samples) as u8;
+        }
+    }
+
+    fn scale(&self, v: i32) -> i32 {
+        v * self.factor as i32
+    }
+
+    /// Draw a shape at sample resolution
+    fn raster(&mut self, shape: impl FnOnce(&mut Raster<'_, GrayImage>)) {
+        let mut raster = Raster::new(&mut self.img);
+        shape(&mut raster);
+        #[cfg(feature = "stats")]
+        {
+            self.samples += raster.pixels_written();
+        }
+    }
+}
+
+impl Canvas for Supersampled {
+    fn fill(&mut self, color: Color) {
+        Raster::new(&mut self.img).fill(color);
+    }
+
+    /// Samples written, in screen pixels (rounded up)
+    #[cfg(feature = "stats")]
+    fn pixels_written(&self) -> u64 {
+        self.samples.div_ceil(u64::from(self.factor * self.factor))
+    }
+
+    fn rounded_rect_corners(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radii: [(u32, u32); 4],
+        color: Color,
+    ) {
+        let f = self.factor;
+        let (x, y) = (self.scale(x), self.scale(y));
+        let radii = radii.map(|(rx, ry)| (rx * f, ry * f));
+        self.raster(|r| r.rounded_rect_corners(x, y, width * f, height * f, radii, color));
+    }
+
+    fn triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32, color: Color) {
+        let [x1, y1, x2, y2, x3, y3] = [x1, y1, x2, y2, x3, y3].map(|v| self.scale(v));
+        self.raster(|r| r.triangle(x1, y1, x2, y2, x3, y3, color));
+    }
+
+    fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, thickness: u32, color: Color) {
+        // Endpoints at pixel centres
+        let half = self.factor as i32 / 2;
+        let [x0, y0, x1, y1] = [x0, y0, x1, y1].map(|v| self.scale(v) + half);
+        let thickness = thickness.max(1) * self.factor;
+        self.raster(|r| r.line(x0, y0, x1, y1, thickness, color));
+    }
+
+    fn text(&mut self, x: i32, y: i32, text: &str, color: Color) {
+        let f = self.factor;
+        let (x, y) = (self.scale(x), self.scale(y));
+        self.raster(|r| {
+            // Each font pixel becomes an f x f block
+            for (py, x0, x1) in text_runs(text, 0, 0) {
+                let (width, top) = ((x1 - x0) as u32 * f, y + py * f as i32);
+                r.rounded_rect_corners(x + x0 * f as i32, top, width, f, [(0, 0); 4], color);
             }
+        });
+    }
+}
+
+/// [`Canvas`] wrapper tallying the work done by another canvas
+#[cfg(feature = "stats")]
+pub(crate) struct Counting<'a, C> {
+    inner: &'a mut C,
+    stats: crate::DrawStats,
+}
+
+#[cfg(feature = "stats")]
+impl<'a, C: Canvas> Counting<'a, C> {
+    pub fn new(inner: &'a mut C) -> Self {
+        Self {
+            inner,
+            stats: crate::DrawStats::default(),
+        }
+    }
+
+    /// Shapes drawn, and the pixels written by them, since [`new()`](Self::new)
+    pub fn finish(self) -> crate::DrawStats {
+        crate::DrawStats {
+            pixels_written: self.inner.pixels_written(),
+            ..self.stats
+        }
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<C: Canvas> Canvas for Counting<'_, C> {
+    fn fill(&mut self, color: Color) {
+        self.inner.fill(color);
+    }
+
+    fn rounded_rect_corners(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radii: [(u32, u32); 4],
+        color: Color,
+    ) {
+        self.inner
+            .rounded_rect_corners(x, y, width, height, radii, color);
+        self.stats.rects_drawn += 1;
+    }
+
+    fn rounded_rect_xy(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radius_x: u32,
+        radius_y: u32,
+        color: Color,
+    ) {
+        self.inner
+            .rounded_rect_xy(x, y, width, height, radius_x, radius_y, color);
+        self.stats.rects_drawn += 1;
+    }
+
+    fn rounded_rect(&mut self, x: i32, y: i32, width: u32, height: u32, radius: u32, color: Color) {
+        self.inner.rounded_rect(x, y, width, height, radius, color);
+        self.stats.rects_drawn += 1;
+    }
+
+    fn triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32, color: Color) {
+        self.inner.triangle(x1, y1, x2, y2, x3, y3, color);
+        self.stats.triangles_drawn += 1;
+    }
+
+    fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, thickness: u32, color: Color) {
+        self.inner.line(x0, y0, x1, y1, thickness, color);
+    }
+
+    fn text(&mut self, x: i32, y: i32, text: &str, color: Color) {
+        self.inner.text(x, y, text, color);
+    }
+}
+
+/// [`Canvas`] emitting SVG elements instead of pixels
+pub(crate) struct Svg {
+    width: u32,
+    height: u32,
+    body: String,
+}
+
+impl Svg {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            body: String::new(),
+        }
+    }
+
+    /// Complete SVG document
+    pub fn finish(self) -> String {
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {w} {h}\" width=\"{w}\" height=\"{h}\">\n{body}</svg>\n",
+            w = self.width,
+            h = self.height,
+            body = self.body
+        )
+    }
+}
+
+/// SVG `#rrggbb` for a gray level
+fn svg_color(color: Color) -> String {
+    format!("#{color:02x}{color:02x}{color:02x}")
+}
+
+impl Canvas for Svg {
+    fn fill(&mut self, color: Color) {
+        // Drawn as a path so `<rect>` elements are only the shapes
+        self.body.clear();
+        let _ = writeln!(
+            self.body,
+            "<path d=\"M0 0H{}V{}H0Z\" fill=\"{}\"/>",
+            self.width,
+            self.height,
+            svg_color(color)
+        );
+    }
+
+    fn rounded_rect_corners(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radii: [(u32, u32); 4],
+        color: Color,
+    ) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        let radii = radii.map(|(rx, ry)| (rx.min(width / 2), ry.min(height / 2)));
+        let [tl, tr, bl, br] = radii;
+
+        if radii.iter().all(|&r| r == tl) {
+            let _ = writeln!(
+                self.body,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\" rx=\"{}\" ry=\"{}\" fill=\"{}\"/>",
+                tl.0,
+                tl.1,
+                svg_color(color)
+            );
+            return;
+        }
+
+        // Clockwise from the end of the top-left corner
+        let (r, b) = (x + width as i32, y + height as i32);
+        let _ = writeln!(
+            self.body,
+            "<path d=\"M{} {y}H{}A{} {} 0 0 1 {r} {}V{}A{} {} 0 0 1 {} {b}H{}A{} {} 0 0 1 {x} {}V{}A{} {} 0 0 1 {} {y}Z\" fill=\"{}\"/>",
+            x + tl.0 as i32,
+            r - tr.0 as i32,
+            tr.0,
+            tr.1,
+            y + tr.1 as i32,
+            b - br.1 as i32,
+            br.0,
+            br.1,
+            r - br.0 as i32,
+            x + bl.0 as i32,
+            bl.0,
+            bl.1,
+            b - bl.1 as i32,
+            y + tl.1 as i32,
+            tl.0,
+            tl.1,
+            x + tl.0 as i32,
+            svg_color(color)
+        );
+    }
+
+    fn triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32, color: Color) {
+        let _ = writeln!(
+            self.body,
+            "<polygon points=\"{x1},{y1} {x2},{y2} {x3},{y3}\" fill=\"{}\"/>",
+            svg_color(color)
+        );
+    }
+
+    fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, thickness: u32, color: Color) {
+        // Offset to pixel centres to match the raster brush
+        let _ = writeln!(
+            self.body,
+            "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"square\"/>",
+            x0 as f32 + 0.5,
+            y0 as f32 + 0.5,
+            x1 as f32 + 0.5,
+            y1 as f32 + 0.5,
+            svg_color(color),
+            thickness.max(1)
+        );
+    }
+
+    fn text(&mut self, x: i32, y: i32, text: &str, color: Color) {
+        // One path for the whole string, a unit-high box per run
+        let mut d = String::new();
+        for (py, x0, x1) in text_runs(text, x, y) {
+            let _ = write!(d, "M{x0} {py}H{x1}V{}H{x0}Z", py + 1);
+        }
+        if !d.is_empty() {
+            let _ = writeln!(self.body, "<path d=\"{d}\" fill=\"{}\"/>", svg_color(color));
         }
     }
 }
@@ -167,13 +1208,35 @@ mod tests {
     #[test]
     fn test_corner_detection() {
         // Corner point (0,0) should be inside the rounded corner
-        assert!(is_in_rounded_corner(0, 0, 10, 10, 5));
+        assert!(is_in_rounded_corner(0, 0, 10, 10, &[(5, 5); 4]));
 
         // Center point should NOT be inside any corner
-        assert!(!is_in_rounded_corner(5, 5, 10, 10, 5));
+        assert!(!is_in_rounded_corner(5, 5, 10, 10, &[(5, 5); 4]));
 
         // Point in the middle of the rectangle should NOT be in a corner
-        assert!(!is_in_rounded_corner(4, 4, 10, 10, 5));
+        assert!(!is_in_rounded_corner(4, 4, 10, 10, &[(5, 5); 4]));
+    }
+
+    #[test]
+    fn test_elliptical_corner() {
+        // Taller vertical radius reaches further down the side
+        assert!(!is_in_rounded_corner(0, 6, 20, 20, &[(4, 4); 4]));
+        assert!(is_in_rounded_corner(0, 6, 20, 20, &[(4, 8); 4]));
+    }
+
+    #[test]
+    fn test_large_corner_radius() {
+        let mut img = GrayImage::new(600, 600);
+        draw_rounded_rect(&mut img, 600, 600, 0, 0, 600, 600, 250, 255);
+        assert_eq!(img.get_pixel(0, 0)[0], 0);
+        assert_eq!(img.get_pixel(300, 300)[0], 255);
+
+        // Supersampling scales the radii up further
+        let mut canvas = Supersampled::new(600, 600, 4);
+        canvas.rounded_rect(0, 0, 600, 600, 250, 255);
+        canvas.resolve(&mut img);
+        assert_eq!(img.get_pixel(0, 0)[0], 0);
+        assert_eq!(img.get_pixel(300, 300)[0], 255);
     }
 
     #[test]
@@ -191,4 +1254,108 @@ mod tests {
         // Center should be filled
         assert_eq!(img.get_pixel(50, 50)[0], 255);
     }
+
+    #[test]
+    fn test_triangle_degenerate() {
+        // All three vertices on one row
+        let mut img = GrayImage::new(20, 20);
+        draw_triangle(&mut img, 20, 20, 2, 5, 12, 5, 7, 5, 255);
+        for x in 2..=12 {
+            assert_eq!(img.get_pixel(x, 5)[0], 255);
+        }
+        assert_eq!(img.get_pixel(7, 4)[0], 0);
+        assert_eq!(img.get_pixel(7, 6)[0], 0);
+
+        // Sliver that misses every pixel centre, partly off-screen
+        let mut img = GrayImage::new(20, 20);
+        draw_triangle(&mut img, 20, 20, -10, 10, 30, 11, 30, 10, 255);
+        assert_eq!(img.get_pixel(0, 10)[0], 255);
+        assert_eq!(img.get_pixel(19, 10)[0], 255);
+    }
+
+    #[test]
+    fn test_line_horizontal() {
+        let mut img = GrayImage::new(20, 20);
+        draw_line(&mut img, 20, 20, 2, 5, 12, 5, 1, 255);
+
+        assert_eq!(img.get_pixel(2, 5)[0], 255);
+        assert_eq!(img.get_pixel(7, 5)[0], 255);
+        assert_eq!(img.get_pixel(12, 5)[0], 255);
+        assert_eq!(img.get_pixel(13, 5)[0], 0);
+        assert_eq!(img.get_pixel(7, 4)[0], 0);
+    }
+
+    #[test]
+    fn test_line_vertical_thick() {
+        let mut img = GrayImage::new(20, 20);
+        draw_line(&mut img, 20, 20, 10, 2, 10, 12, 3, 255);
+
+        assert_eq!(img.get_pixel(10, 2)[0], 255);
+        assert_eq!(img.get_pixel(10, 7)[0], 255);
+        assert_eq!(img.get_pixel(10, 12)[0], 255);
+        // Thickness spreads one pixel either side
+        assert_eq!(img.get_pixel(9, 7)[0], 255);
+        assert_eq!(img.get_pixel(11, 7)[0], 255);
+        assert_eq!(img.get_pixel(12, 7)[0], 0);
+    }
+
+    #[test]
+    fn test_line_diagonal() {
+        let mut img = GrayImage::new(20, 20);
+        draw_line(&mut img, 20, 20, 0, 0, 10, 10, 1, 255);
+
+        assert_eq!(img.get_pixel(0, 0)[0], 255);
+        assert_eq!(img.get_pixel(5, 5)[0], 255);
+        assert_eq!(img.get_pixel(10, 10)[0], 255);
+        assert_eq!(img.get_pixel(5, 6)[0], 0);
+    }
+
+    #[test]
+    fn test_line_clipping() {
+        let mut img = GrayImage::new(20, 20);
+
+        // Partially off-screen: the visible part is still drawn
+        draw_line(&mut img, 20, 20, -10, 4, 30, 4, 1, 255);
+        assert_eq!(img.get_pixel(0, 4)[0], 255);
+        assert_eq!(img.get_pixel(10, 4)[0], 255);
+        assert_eq!(img.get_pixel(19, 4)[0], 255);
+
+        // Fully off-screen lines are ignored without panicking
+        let before = img.clone();
+        draw_line(&mut img, 20, 20, -50, -50, -5, -30, 4, 255);
+        draw_line(&mut img, 20, 20, 25, 0, 40, 19, 1, 255);
+        assert_eq!(img, before);
+    }
+
+    #[test]
+    fn test_text() {
+        let mut img = GrayImage::new(12, 8);
+        draw_text(&mut img, 12, 8, 1, 1, "hi", 255);
+        assert_eq!(text_width("HI"), 7);
+        // H: two full columns and a middle bar, I: bars with a stem
+        assert_eq!(img.pixels().filter(|p| p[0] == 255).count(), 11 + 9);
+        assert_eq!(img.get_pixel(1, 1)[0], 255);
+        assert_eq!(img.get_pixel(2, 1)[0], 0);
+        assert_eq!(img.get_pixel(2, 3)[0], 255);
+        assert_eq!(img.get_pixel(6, 3)[0], 255);
+        assert_eq!(img.get_pixel(5, 3)[0], 0);
+
+        // Clipped at the edges, unknown characters blank
+        let mut img = GrayImage::new(12, 8);
+        draw_text(&mut img, 12, 8, 10, 6, "#A", 255);
+        assert!(img.pixels().all(|p| p[0] == 0));
+        draw_text(&mut img, 12, 8, -2, 0, "A", 255);
+        assert!(img.pixels().any(|p| p[0] == 255));
+    }
+
+    #[test]
+    fn test_crossfade() {
+        let lit = GrayImage::from_pixel(8, 4, image::Luma([255]));
+        let blank = GrayImage::new(8, 4);
+
+        let half = crossfade(&lit, &blank, 0.5);
+        assert!(half.pixels().all(|p| (127..=128).contains(&p[0])));
+        assert_eq!(crossfade(&lit, &blank, 0.0), lit);
+        assert_eq!(crossfade(&lit, &blank, 2.0), blank);
+    }
 }
@@ -0,0 +1,45 @@
+//! Error module - Configuration errors
+//!
+//! Returned by setters that validate their input against the screen size.
+
+use std::fmt;
+
+/// Invalid eye configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotEyesError {
+    /// Eye width or height is zero
+    ZeroSize,
+    /// Eyes of this size don't fit on the screen
+    EyeTooLarge { width: u32, height: u32 },
+    /// Space between the eyes pushes the row off the screen
+    SpacingTooLarge { space: u32 },
+    /// Image buffer doesn't match the output size
+    BufferSize {
+        expected: (u32, u32),
+        actual: (u32, u32),
+    },
+}
+
+impl fmt::Display for BotEyesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroSize => write!(f, "eye width and height must be non-zero"),
+            Self::EyeTooLarge { width, height } => {
+                write!(f, "{width}x{height} eyes don't fit on the screen")
+            }
+            Self::SpacingTooLarge { space } => {
+                write!(
+                    f,
+                    "space between eyes of {space}px doesn't fit on the screen"
+                )
+            }
+            Self::BufferSize { expected, actual } => write!(
+                f,
+                "buffer is {}x{} but the output is {}x{}",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BotEyesError {}
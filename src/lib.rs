@@ -6,7 +6,7 @@
 //! ## Features
 //!
 //! - **Eyes Rendering**: Draw left and right eyes with customizable size, position, and border radius
-//! - **Mood Expressions**: Default, Angry, Happy, Confuse, Sad, Surprise, Loading
+//! - **Mood Expressions**: Default, Angry, Happy, Confuse, Sad, Surprise, Loading, Skeptical
 //! - **Eye Positions**: 8 predefined directions (N, NE, E, SE, S, SW, W, NW) + Center
 //! - **Animations**: Blink, Confused (horizontal shake), Laugh (vertical shake), Sweat drops
 //! - **Special Modes**: Cyclops (single eye), Curious (eyes grow when looking sideways)
@@ -14,7 +14,7 @@
 //!
 //! ## Quick Start
 //!
-//! ```rust
+//! ```rust,no_run
 //! use boteyes::{RoboEyes, Mood};
 //!
 //! // Create eyes instance (128x64 OLED resolution)
@@ -29,6 +29,7 @@
 //!
 //! // Save to file
 //! img.save("happy_eyes.png")?;
+//! # Ok::<(), image::ImageError>(())
 //! ```
 //!
 //! ## Architecture
@@ -36,8 +37,10 @@
 //! The library is organized into modules:
 //!
 //! - [`types`]: Enums (Mood, Position) and config structs
-//! - [`draw`]: Graphics primitives (rounded rect, triangle)
+//! - [`draw`]: Graphics primitives (rounded rect, triangle, line), also usable for custom overlays
+//! - [`error`]: Configuration errors
 //! - [`animation`]: Sweat drop animation state
+//! - `runtime`: Interactive preview window (requires the `demo-runtime` feature)
 //!
 //! The drawing pipeline:
 //!
@@ -48,22 +51,108 @@
 //!
 
 mod animation;
-mod draw;
+pub mod draw;
+mod error;
+#[cfg(feature = "demo-runtime")]
+pub mod runtime;
 mod types;
 
+pub use draw::{
+    PixelSink, RawBuffer, crossfade, draw_line, draw_rounded_rect, draw_rounded_rect_corners,
+    draw_rounded_rect_xy, draw_text, draw_triangle, text_width,
+};
+pub use error::BotEyesError;
+
+#[cfg(feature = "stats")]
+pub use types::DrawStats;
+
 pub use types::{
-    BlinkConfig, EyeGeometry, IdleConfig, Mood, Position, RoboEyesConfig, ScreenConstraints,
+    Anim, AnimKind, BlinkConfig, CornerRadii, Easing, Emotion, EyeGeometry, HappyStyle, IdleConfig,
+    LaughStyle, Mood, Position, Rect, RoboEyesConfig, RoboEyesSettings, Rotation,
+    ScreenConstraints, Side,
 };
 
-use animation::{SweatDrops, SweatPosition};
-use draw::{draw_rounded_rect, draw_triangle};
+use animation::{
+    DEFAULT_SWEAT_COUNT, DEFAULT_SWEAT_GRAVITY, DEFAULT_SWEAT_SPEED, SweatDrops, SweatRegion,
+};
+use draw::{Canvas, Raster, Supersampled, Svg};
+use types::half_rounded;
 
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
 
-// Color constants for display rendering
+// Default colors for display rendering
 const BGCOLOR: u8 = 0;
 const MAINCOLOR: u8 = 255;
 
+// Scanner mode: bar width, time to cross the screen once, trail length
+const SCANNER_WIDTH: u32 = 6;
+const SCANNER_SWEEP_MS: u64 = 1000;
+const SCANNER_TRAIL: i32 = 4;
+
+// Samples per pixel along each axis when anti-aliasing
+const ANTIALIAS_FACTOR: u32 = 4;
+
+// Thinking spinner: dot size and orbit radius in pixels
+const THINKING_DOT_SIZE: u32 = 3;
+const THINKING_ORBIT_RADIUS: u32 = 4;
+
+// Status text: gap below it, and how long a too-wide line takes to
+// scroll by one pixel
+const STATUS_MARGIN: u32 = 1;
+const STATUS_SCROLL_MS: u64 = 60;
+
+// How long the acknowledge gesture looks down before returning
+const ACKNOWLEDGE_NOD_MS: u64 = 300;
+
+// Delay between the two blinks of an automatic double blink
+const DOUBLE_BLINK_GAP_MS: u64 = 200;
+
+// Startle: how long the eyes stay up, how far they jump and grow
+const STARTLE_MS: u64 = 250;
+const STARTLE_JUMP: i32 = 8;
+const STARTLE_GROW: u32 = 8;
+
+// Wake-up script: (ms since start, fraction of the open height). The
+// eyes open slowly with two flutters, then the sequence ends at WAKE_MS
+const WAKE_STEPS: [(u64, f32); 8] = [
+    (0, 0.0),
+    (300, 0.15),
+    (500, 0.3),
+    (700, 0.1),
+    (850, 0.5),
+    (1000, 0.7),
+    (1150, 0.3),
+    (1300, 1.0),
+];
+const WAKE_MS: u64 = 1500;
+
+// Wince: fraction of the open height the squeezed eyes keep, and the
+// length of the wrinkle lines at the outer corners
+const WINCE_FRACTION: f32 = 0.15;
+const WINCE_LINE: i32 = 6;
+
+// Longest any one-shot animation (laugh, confused, shiver, ...) may run
+const ANIM_WATCHDOG_MS: u64 = 60_000;
+
+// Heartbeat: how much the eyes grow on the "lub" beat, in pixels
+const HEARTBEAT_AMPLITUDE: f32 = 3.0;
+
+// Pupil size multipliers for excited (Happy/Surprise) and Angry moods
+const PUPIL_DILATED: f32 = 1.4;
+const PUPIL_CONSTRICTED: f32 = 0.6;
+
+// How long an eased position or height transition takes
+const EASING_MS: u64 = 160;
+
+// Frame interval a custom tween factor refers to (~60 FPS)
+const TWEEN_FRAME_MS: u64 = 16;
+
+// Random delay between micro-saccades
+const SACCADE_MIN_MS: u64 = 200;
+const SACCADE_MAX_MS: u64 = 500;
+
 /// Main RoboEyes struct
 ///
 /// Contains all state for rendering robot eyes:
@@ -77,16 +166,59 @@ const MAINCOLOR: u8 = 255;
 ///
 /// The library uses millisecond timestamps for animation timing.
 /// Pass `current_time` to [`draw_eyes()`] to advance animations.
+///
+/// ## Cloning
+///
+/// Cloning duplicates the entire animation state, including tween values
+/// and timers, so a clone continues exactly where the original left off.
+/// [`Default`] builds a 128x64 instance.
+#[derive(Clone)]
 pub struct RoboEyes {
     // Display dimensions
     screen_width: u32,
     screen_height: u32,
 
+    // Configuration the instance was built with (used by reset)
+    config: RoboEyesConfig,
+
     // Current animation time (milliseconds)
     current_time: u64,
 
+    // Random source for autoblink, idle and glitch effects
+    rng: StdRng,
+
+    // Pause state: timestamps passed to draw_into are shifted back by
+    // time_offset so timers don't race ahead while paused
+    paused: bool,
+    paused_at: Option<u64>,
+    time_offset: u64,
+    // Last timestamp passed in by the caller, before the pause shift
+    last_external_time: u64,
+
+    // Custom tween speed: factor per reference frame, and the factor
+    // actually applied this frame after scaling by the frame delta
+    tween_factor: Option<f32>,
+    tween_step: Option<f32>,
+    // Time since the previous frame
+    frame_ms: u64,
+    // Same pair for eye positions only, overriding the above for gaze
+    gaze_factor: Option<f32>,
+    gaze_step: Option<f32>,
+    // Gaze targets closer than this to the current one are ignored
+    gaze_deadzone: u32,
+    // Inset of the gaze box from the screen edges
+    gaze_margin: u32,
+    last_update: Option<u64>,
+    // Hash of the last frame drawn with draw_into_checked
+    last_frame_hash: Option<u64>,
+    // Work done drawing the last frame
+    #[cfg(feature = "stats")]
+    draw_stats: DrawStats,
+
     // Mood state
     mood: Mood,
+    // Blink once when the mood changes
+    mood_transition_effect: bool,
 
     // Eye geometry
     eye_l: EyeGeometry,
@@ -109,10 +241,32 @@ pub struct RoboEyes {
     eye_r_height_default: u32,
     eye_r_height_current: u32,
     eye_r_height_next: u32,
+    // Fraction of the default height open eyes settle at (squint)
+    eye_height_ratio: f32,
+    // Height of a fully closed eye
+    closed_height: u32,
+    // Per-eye multiplier on how fast heights change
+    eye_l_speed: f32,
+    eye_r_speed: f32,
+
+    // Time-based height animation, used when a blink speed is set
+    blink_speed: Option<(u64, u64)>,
+    eye_l_height_timed: TimedTween,
+    eye_r_height_timed: TimedTween,
+
+    // Curve-based transitions, used when an easing is set. The right eye
+    // position follows the left one
+    easing: Option<Easing>,
+    eye_l_x_eased: EasedTween,
+    eye_l_y_eased: EasedTween,
+    eye_l_height_eased: EasedTween,
+    eye_r_height_eased: EasedTween,
 
     // Height offset for curious mode
     eye_l_height_offset: u32,
     eye_r_height_offset: u32,
+    curious_amount: u32,
+    curious_threshold: i32,
 
     // Eye scale for confuse mood (one eye bigger than others)
     eye_l_scale: f32,
@@ -120,10 +274,27 @@ pub struct RoboEyes {
     eye_r_scale: f32,
     eye_r_scale_next: f32,
 
+    // Pupils: base size as a fraction of the largest pupil that fits, and
+    // the current size after the mood-driven dilation
+    pupils: bool,
+    pupil_dilation: f32,
+    pupil_size: f32,
+    // Iris ring as (inner radius, outer radius, color)
+    iris: Option<(u32, u32, u8)>,
+
+    // Number of eyes in the row (1 = cyclops)
+    eye_count: u8,
+
     // Space between eyes
     space_between: u32,
     space_between_next: u32,
 
+    // Border radius tween targets (left, right) and the radii set by the user
+    border_radius_next: (u32, u32),
+    border_radius_default: (u32, u32),
+    // Nudge the border radius toward a per-mood value
+    mood_affects_radius: bool,
+
     // Eyelid states (for tweening)
     eyelids_tired_height: u32,
     eyelids_tired_height_next: u32,
@@ -131,6 +302,11 @@ pub struct RoboEyes {
     eyelids_angry_height_next: u32,
     eyelids_happy_bottom_offset: u32,
     eyelids_happy_bottom_offset_next: u32,
+    happy_style: HappyStyle,
+    eyelids_skeptical_height: u32,
+    eyelids_skeptical_height_next: u32,
+    // Fraction of the eye height the tired/angry/happy eyelids cover
+    mood_intensity: f32,
 
     // Eye open/close state
     eye_l_open: bool,
@@ -138,6 +314,11 @@ pub struct RoboEyes {
 
     // Mode flags
     cyclops: bool,
+    // Cyclops transition: 0.0 shows both eyes, 1.0 has the right eye and
+    // spacing fully collapsed
+    cyclops_progress: f32,
+    // Which eye survives in cyclops mode
+    cyclops_eye: Side,
     curious: bool,
     sweat: bool,
 
@@ -145,11 +326,31 @@ pub struct RoboEyes {
     autoblinker: bool,
     blink_config: BlinkConfig,
     blink_timer: u64,
+    // When the second half of a double blink is due
+    double_blink_at: Option<u64>,
 
     // Idle mode
     idle: bool,
     idle_config: IdleConfig,
     idle_timer: u64,
+    idle_bias: f32,
+    // Idle return: when the gaze heads back to centre between moves
+    idle_return: bool,
+    idle_return_at: Option<u64>,
+    // Jump to idle targets instead of tweening there
+    idle_instant: bool,
+
+    // Shift of the whole eye cluster on top of the gaze position
+    cluster_offset: (i32, i32),
+
+    // Micro-saccades: small random offset on top of the gaze target.
+    // saccade_target is the target we last wrote, to tell whether anything
+    // else has moved the gaze since
+    saccades: bool,
+    saccade_amplitude: u32,
+    saccade_timer: u64,
+    saccade_offset: (i32, i32),
+    saccade_target: (i32, i32),
 
     // Horizontal flicker (shaking)
     h_flicker: bool,
@@ -161,20 +362,161 @@ pub struct RoboEyes {
     v_flicker_amplitude: u32,
     v_flicker_alternate: bool,
 
+    // Distance flickering eyes keep from the screen edge
+    flicker_margin: u32,
+
     // Confused animation (horizontal shake)
     confused: bool,
     confused_timer: u64,
     confused_duration: u64,
+    // Duration for this run only, from anim_confused_for
+    confused_duration_once: Option<u64>,
     confused_toggle: bool,
+    confused_amplitude: u32,
 
-    // Laugh animation (vertical bounce)
+    // Laugh animation (bounce along the chosen axes)
     laugh: bool,
     laugh_timer: u64,
     laugh_duration: u64,
+    // Duration for this run only, from anim_laugh_for
+    laugh_duration_once: Option<u64>,
     laugh_toggle: bool,
+    laugh_amplitude: u32,
+    laugh_style: LaughStyle,
+    // Style of the running laugh, so a change mid-laugh keeps shaking the same axes
+    laugh_running_style: LaughStyle,
+
+    // Shiver animation (small horizontal + vertical tremble)
+    shiver: bool,
+    shiver_timer: u64,
+    shiver_duration: u64,
+    shiver_toggle: bool,
+
+    // Scripted animation sequence
+    anim_queue: VecDeque<Anim>,
+    anim_wait_until: u64,
+
+    // Blink pattern: remaining (closed, duration) steps, and when the
+    // current step ends
+    blink_pattern: VecDeque<(bool, u64)>,
+    blink_pattern_until: Option<u64>,
+
+    // Scripted gaze: waypoints, the next one to visit and when to move on
+    path: Vec<(i32, i32, u64)>,
+    path_step: usize,
+    path_until: Option<u64>,
+    path_loop: bool,
+
+    // Acknowledge gesture (nod down + blink)
+    acknowledge: bool,
+    acknowledge_timer: u64,
+    acknowledge_toggle: bool,
+    // Gaze target saved while the nod runs; kept across re-triggers
+    acknowledge_prev: Option<(i32, i32)>,
+
+    // Startle (jump up + grow)
+    startle: bool,
+    startle_timer: u64,
+    startle_toggle: bool,
+    // Vertical gaze target saved while the eyes are up; kept across
+    // re-triggers so the eyes always settle back to the original target
+    startle_prev_y: Option<i32>,
+
+    // Peek: slide in from off-screen to the centre
+    peek: bool,
+    peek_from: Position,
+    peek_timer: u64,
+    peek_toggle: bool,
 
-    // Sweat animation state (3 drops)
+    // Wince (eyes squeezed shut with corner wrinkles)
+    wince: bool,
+    wince_timer: u64,
+    wince_toggle: bool,
+    wince_duration: u64,
+
+    // Wake-up sequence
+    wake: bool,
+    wake_timer: u64,
+    wake_toggle: bool,
+
+    // Sweat animation state
     sweat_drops: SweatDrops,
+    sweat_region: SweatRegion,
+
+    // Output orientation
+    flip_horizontal: bool,
+    flip_vertical: bool,
+    rotation: Rotation,
+
+    // Colors (grayscale levels)
+    fg_color: u8,
+    bg_color: u8,
+
+    // Sweat drop appearance (color defaults to fg_color)
+    sweat_color: Option<u8>,
+    sweat_radius: u32,
+    // Sweat drop fall speed (px/ms) and acceleration (px/ms²)
+    sweat_speed: f32,
+    sweat_gravity: f32,
+
+    // Swap foreground and background when drawing
+    inverted: bool,
+
+    // Smooth edges by supersampling
+    antialias: bool,
+
+    // Colors for the RGB output, indexed by gray level
+    palette: Option<Vec<Rgb<u8>>>,
+    // Top and bottom eye colors for the RGB output
+    eye_gradient: Option<(Rgb<u8>, Rgb<u8>)>,
+    // Output row and the lit colors above / below it, for two-tone panels
+    split_color: Option<(u32, u8, u8)>,
+
+    // Gray level at or above which a pixel is lit in 1-bpp output
+    binary_threshold: u8,
+
+    // Drop shadow offset down-right under the eyes
+    shadow: bool,
+    shadow_offset: i32,
+    shadow_intensity: u8,
+
+    // Mood-dependent eyebrows
+    eyebrows: bool,
+
+    // Scanning bar replacing the eyes
+    scanner: bool,
+
+    // "Thinking" dot orbiting above the eyes
+    thinking: bool,
+    thinking_period: u64,
+
+    // Status line drawn under the eyes
+    status_text: String,
+
+    // Pixel width / height, for corner correction on non-square pixels
+    pixel_aspect: f32,
+
+    // Event callbacks
+    blink_in_progress: bool,
+    on_blink_complete: Callback,
+    on_idle_move: Callback,
+
+    // Glitch post-process effect
+    glitch: bool,
+    glitch_intensity: u8,
+
+    // Breathing idle pulse (eye height oscillation)
+    breathing: bool,
+    breathing_amplitude: u32,
+    breathing_period: u64,
+
+    // Heartbeat pulse (lub-dub eye height bumps)
+    heartbeat: bool,
+    heartbeat_bpm: u32,
+
+    // ASCII renderer cell size (pixels per character)
+    ascii_cell_width: u32,
+    ascii_cell_height: u32,
 }
 
 impl RoboEyes {
@@ -225,19 +567,47 @@ impl RoboEyes {
         let default_space = config.space_between;
 
         // Calculate initial position (centered)
-        let eye_l_x = ((screen_width as i32)
-            - default_width as i32
-            - default_space as i32
-            - default_width as i32)
-            / 2;
-        let eye_l_y = ((screen_height as i32) - default_height as i32) / 2;
+        let constraints = ScreenConstraints::new(screen_width, screen_height);
+        let eye_l_x = constraints.center_x(default_width, default_space, default_width);
+        let eye_l_y = constraints.center_y(default_height);
+        // Open eyes start in place; shut ones slide and grow into it
+        let start_open = config.start_open;
+        let (start_height, eye_r_x) = if start_open {
+            (
+                default_height,
+                eye_l_x + (default_width + default_space) as i32,
+            )
+        } else {
+            (1, 0)
+        };
 
         Self {
             screen_width,
             screen_height,
+            config,
             current_time: 0,
 
+            rng: StdRng::from_entropy(),
+
+            paused: false,
+            paused_at: None,
+            time_offset: 0,
+            last_external_time: 0,
+
+            tween_factor: None,
+            tween_step: None,
+            frame_ms: TWEEN_FRAME_MS,
+            gaze_factor: None,
+            gaze_step: None,
+            gaze_deadzone: 0,
+            gaze_margin: 0,
+            last_update: None,
+            last_frame_hash: None,
+            #[cfg(feature = "stats")]
+            draw_stats: DrawStats::default(),
+
             mood: Mood::Default,
+            mood_transition_effect: false,
 
             eye_l: EyeGeometry::new(default_width, default_height, default_border_radius),
             eye_r: EyeGeometry::new(default_width, default_height, default_border_radius),
@@ -247,50 +617,95 @@ impl RoboEyes {
             eye_l_x_next: eye_l_x,
             eye_l_y_next: eye_l_y,
 
-            eye_r_x: 0,
+            eye_r_x,
             eye_r_y: eye_l_y,
-            eye_r_x_next: 0,
+            eye_r_x_next: eye_r_x,
             eye_r_y_next: eye_l_y,
 
             eye_l_height_default: default_height,
-            eye_l_height_current: 1,
+            eye_l_height_current: start_height,
             eye_l_height_next: default_height,
             eye_r_height_default: default_height,
-            eye_r_height_current: 1,
+            eye_r_height_current: start_height,
             eye_r_height_next: default_height,
+            eye_height_ratio: 1.0,
+            closed_height: 1,
+            eye_l_speed: 1.0,
+            eye_r_speed: 1.0,
 
             eye_l_height_offset: 0,
             eye_r_height_offset: 0,
+            blink_speed: None,
+            eye_l_height_timed: TimedTween::default(),
+            eye_r_height_timed: TimedTween::default(),
+
+            easing: None,
+            eye_l_x_eased: EasedTween::default(),
+            eye_l_y_eased: EasedTween::default(),
+            eye_l_height_eased: EasedTween::default(),
+            eye_r_height_eased: EasedTween::default(),
+
+            curious_amount: 8,
+            curious_threshold: 10,
 
             eye_l_scale: 1.0,
             eye_l_scale_next: 1.0,
             eye_r_scale: 1.0,
             eye_r_scale_next: 1.0,
 
+            pupils: false,
+            pupil_dilation: 0.5,
+            pupil_size: 0.5,
+            iris: None,
+
+            eye_count: 2,
+
             space_between: default_space,
             space_between_next: default_space,
 
+            border_radius_next: (default_border_radius, default_border_radius),
+            border_radius_default: (default_border_radius, default_border_radius),
+            mood_affects_radius: false,
+
             eyelids_tired_height: 0,
             eyelids_tired_height_next: 0,
             eyelids_angry_height: 0,
             eyelids_angry_height_next: 0,
             eyelids_happy_bottom_offset: 0,
             eyelids_happy_bottom_offset_next: 0,
+            happy_style: HappyStyle::Flat,
+            eyelids_skeptical_height: 0,
+            eyelids_skeptical_height_next: 0,
+            mood_intensity: 0.5,
 
-            eye_l_open: false,
-            eye_r_open: false,
+            eye_l_open: start_open,
+            eye_r_open: start_open,
 
             cyclops: false,
+            cyclops_progress: 0.0,
+            cyclops_eye: Side::Left,
             curious: false,
             sweat: false,
 
             autoblinker: false,
             blink_config: BlinkConfig::default(),
             blink_timer: 0,
+            double_blink_at: None,
 
             idle: false,
             idle_config: IdleConfig::default(),
             idle_timer: 0,
+            idle_bias: 0.0,
+            idle_return: false,
+            idle_return_at: None,
+            idle_instant: false,
+            cluster_offset: (0, 0),
+
+            saccades: false,
+            saccade_amplitude: 1,
+            saccade_timer: 0,
+            saccade_offset: (0, 0),
+            saccade_target: (0, 0),
 
             h_flicker: false,
             h_flicker_amplitude: 2,
@@ -300,31 +715,283 @@ impl RoboEyes {
             v_flicker_amplitude: 10,
             v_flicker_alternate: false,
 
+            flicker_margin: 0,
+
             confused: false,
             confused_timer: 0,
             confused_duration: 500,
+            confused_duration_once: None,
             confused_toggle: true,
+            confused_amplitude: 20,
 
             laugh: false,
             laugh_timer: 0,
             laugh_duration: 500,
+            laugh_duration_once: None,
             laugh_toggle: true,
+            laugh_amplitude: 5,
+            laugh_style: LaughStyle::Vertical,
+            laugh_running_style: LaughStyle::Vertical,
+
+            shiver: false,
+            shiver_timer: 0,
+            shiver_duration: 600,
+            shiver_toggle: true,
+
+            blink_pattern: VecDeque::new(),
+            blink_pattern_until: None,
+
+            path: Vec::new(),
+            path_step: 0,
+            path_until: None,
+            path_loop: false,
+
+            acknowledge: false,
+            acknowledge_timer: 0,
+            acknowledge_toggle: true,
+            acknowledge_prev: None,
+
+            startle: false,
+            startle_timer: 0,
+            startle_toggle: true,
+            startle_prev_y: None,
+            peek: false,
+            peek_from: Position::Center,
+            peek_timer: 0,
+            peek_toggle: true,
+
+            wince: false,
+            wince_timer: 0,
+            wince_toggle: true,
+            wince_duration: 0,
+            wake: false,
+            wake_timer: 0,
+            wake_toggle: true,
+
+            anim_queue: VecDeque::new(),
+            anim_wait_until: 0,
+
+            sweat_drops: SweatDrops::new(
+                screen_width,
+                SweatRegion::for_screen(screen_height),
+                DEFAULT_SWEAT_COUNT,
+            ),
+            sweat_region: SweatRegion::for_screen(screen_height),
 
-            sweat_drops: SweatDrops::new(screen_width),
+            flip_horizontal: false,
+            flip_vertical: false,
+            rotation: Rotation::Deg0,
+
+            fg_color: MAINCOLOR,
+            bg_color: BGCOLOR,
+
+            sweat_color: None,
+            sweat_radius: 3,
+            sweat_speed: DEFAULT_SWEAT_SPEED,
+            sweat_gravity: DEFAULT_SWEAT_GRAVITY,
+
+            inverted: false,
+
+            antialias: false,
+            palette: None,
+            eye_gradient: None,
+            split_color: None,
+            binary_threshold: 128,
+
+            shadow: false,
+            shadow_offset: 2,
+            shadow_intensity: 64,
+
+            eyebrows: false,
+
+            scanner: false,
+
+            thinking: false,
+            thinking_period: 1000,
+
+            status_text: String::new(),
+
+            pixel_aspect: 1.0,
+
+            blink_in_progress: false,
+            on_blink_complete: Callback::default(),
+            on_idle_move: Callback::default(),
+
+            glitch: false,
+            glitch_intensity: 0,
+
+            breathing: false,
+            breathing_amplitude: 2,
+            breathing_period: 4000,
+
+            heartbeat: false,
+            heartbeat_bpm: 60,
+
+            ascii_cell_width: 2,
+            ascii_cell_height: 4,
         }
     }
 
+    /// Restore the freshly-constructed state
+    ///
+    /// Resets mood, positions, sizes, spacing, mode flags and timers to the
+    /// values used when this instance was created, keeping the screen size,
+    /// the original [`RoboEyesConfig`], the random generator and any
+    /// registered callbacks.
+    pub fn reset(&mut self) {
+        let mut fresh =
+            Self::new_with_config(self.screen_width, self.screen_height, self.config.clone());
+        fresh.rng = self.rng.clone();
+        fresh.on_blink_complete = std::mem::take(&mut self.on_blink_complete);
+        fresh.on_idle_move = std::mem::take(&mut self.on_idle_move);
+        *self = fresh;
+    }
+
+    /// Seed the random number generator
+    ///
+    /// Random behaviour (autoblink timing, idle targets, glitch effect)
+    /// becomes reproducible for a given seed.
+    pub fn set_seed(&mut self, seed: u64) -> &mut Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
     // =====================================================================
     // Configuration Setters
+    //
+    // Setters return `&mut Self` so configuration can be chained:
+    // `eyes.set_mood(Mood::Happy).set_cyclops(true).open();`
     // =====================================================================
 
     /// Set the eye mood expression
-    pub fn set_mood(&mut self, mood: Mood) {
+    ///
+    /// With [`set_mood_transition_effect()`](Self::set_mood_transition_effect)
+    /// enabled, an actual change of mood also triggers a single blink.
+    pub fn set_mood(&mut self, mood: Mood) -> &mut Self {
+        if self.mood_transition_effect && mood != self.mood {
+            // Leave closed eyes and blinks already under way alone
+            let blinking = self.eye_l_height_next == self.closed_height
+                || self.eye_r_height_next == self.closed_height;
+            if self.eye_l_open && self.eye_r_open && !blinking {
+                self.blink();
+            }
+        }
         self.mood = mood;
+        self
+    }
+
+    /// Blink once whenever [`set_mood()`](Self::set_mood) changes the mood
+    ///
+    /// Makes mood changes read as deliberate. Setting the same mood again
+    /// doesn't blink.
+    pub fn set_mood_transition_effect(&mut self, enabled: bool) -> &mut Self {
+        self.mood_transition_effect = enabled;
+        self
+    }
+
+    /// Choose the shape of the Happy mood's bottom eyelid (default flat)
+    ///
+    /// [`HappyStyle::Curved`] cuts the eyes with a wide ellipse, so they
+    /// become upturned arches. Both styles ease in the same way.
+    pub fn set_happy_style(&mut self, style: HappyStyle) -> &mut Self {
+        self.happy_style = style;
+        self
+    }
+
+    /// Set how strongly moods show, from 0.0 to 1.0 (default 0.5)
+    ///
+    /// The Tired/Sad and Angry eyelids and the Happy bottom cover this
+    /// fraction of the eye height: 0.3 is a light frown, 1.0 brings the
+    /// lids all the way down for a furious glare. The default matches the classic
+    /// half-covered look. Values outside the range are clamped.
+    pub fn set_mood_intensity(&mut self, intensity: f32) -> &mut Self {
+        self.mood_intensity = intensity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Let the mood nudge the eyes' border radius (default off)
+    ///
+    /// Happy and Surprise tween toward twice the radius set with
+    /// [`set_border_radius()`](Self::set_border_radius) (at most fully
+    /// round), Angry toward half of it, for squarer eyes. Other moods keep
+    /// the set radius. Per-corner radii are unaffected.
+    pub fn set_mood_affects_radius(&mut self, enabled: bool) -> &mut Self {
+        self.mood_affects_radius = enabled;
+        self
+    }
+
+    /// Border radius the current mood tweens toward
+    fn mood_radius(&self, base: u32, eye: &EyeGeometry) -> u32 {
+        if !self.mood_affects_radius {
+            return base;
+        }
+        match self.mood {
+            Mood::Happy | Mood::Surprise => (base * 2).min(eye.width.min(eye.height) / 2).max(base),
+            Mood::Angry => base / 2,
+            _ => base,
+        }
+    }
+
+    /// Express a feeling: set a mood plus fitting ambient animations
+    ///
+    /// A shortcut over [`set_mood()`](Self::set_mood),
+    /// [`set_idle_mode()`](Self::set_idle_mode), [`set_sweat()`](Self::set_sweat),
+    /// [`set_saccades()`](Self::set_saccades) and [`set_curiosity()`](Self::set_curiosity).
+    /// Every emotion sets all of these, so switching emotions doesn't leave
+    /// the previous one's effects behind. See [`Emotion`] for the bundles.
+    pub fn express(&mut self, emotion: Emotion) -> &mut Self {
+        match emotion {
+            Emotion::Excited => self
+                .set_mood(Mood::Happy)
+                .set_idle_mode(true, 1, 1, 100, 100)
+                .set_sweat(false)
+                .set_saccades(false, 0)
+                .set_curiosity(true),
+            Emotion::Content => self
+                .set_mood(Mood::Happy)
+                .set_idle_mode(true, 4, 3, 60, 40)
+                .set_sweat(false)
+                .set_saccades(false, 0)
+                .set_curiosity(false),
+            Emotion::Bored => self
+                .set_mood(Mood::Sad)
+                .set_idle_mode(true, 6, 4, 50, 20)
+                .set_sweat(false)
+                .set_saccades(false, 0)
+                .set_curiosity(false),
+            Emotion::Nervous => self
+                .set_mood(Mood::Default)
+                .set_idle_mode(true, 1, 2, 80, 60)
+                .set_sweat(true)
+                .set_saccades(true, 1)
+                .set_curiosity(false),
+        }
     }
 
     /// Set eye size for both eyes
-    pub fn set_size(&mut self, width: u32, height: u32) {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BotEyesError::ZeroSize`] for a zero width or height and
+    /// [`BotEyesError::EyeTooLarge`] if the row of eyes (with the current
+    /// spacing) would not fit on the screen. The size is left unchanged on
+    /// error; use [`set_size_unchecked()`](Self::set_size_unchecked) to skip
+    /// validation.
+    pub fn set_size(&mut self, width: u32, height: u32) -> Result<(), BotEyesError> {
+        if width == 0 || height == 0 {
+            return Err(BotEyesError::ZeroSize);
+        }
+        if self.row_width(width, self.space_between) > self.screen_width as u64
+            || height > self.screen_height
+        {
+            return Err(BotEyesError::EyeTooLarge { width, height });
+        }
+        self.set_size_unchecked(width, height);
+        Ok(())
+    }
+
+    /// Set eye size for both eyes without validation
+    pub fn set_size_unchecked(&mut self, width: u32, height: u32) -> &mut Self {
         self.eye_l.width = width;
         self.eye_r.width = width;
         self.eye_l.height = height;
@@ -334,819 +1001,6531 @@ impl RoboEyes {
         self.eye_r_height_default = height;
         self.eye_l_height_next = height;
         self.eye_r_height_next = height;
+        self
+    }
+
+    /// Set how far open eyes are, from 0.0 (closed) to 1.0 (fully open)
+    ///
+    /// Open eyes ease to `ratio` of their default height and return there
+    /// after each blink, for a half-closed gaze without the Tired eyelids.
+    pub fn set_eye_height_ratio(&mut self, ratio: f32) -> &mut Self {
+        self.eye_height_ratio = ratio.clamp(0.0, 1.0);
+        self.restore_open_heights();
+        self
     }
 
     /// Set border radius for rounded corners
-    pub fn set_border_radius(&mut self, left: u32, right: u32) {
+    ///
+    /// Applies to all four corners, replacing any
+    /// [`set_corner_radii()`](Self::set_corner_radii).
+    pub fn set_border_radius(&mut self, left: u32, right: u32) -> &mut Self {
         self.eye_l.border_radius = left;
         self.eye_r.border_radius = right;
+        self.border_radius_next = (left, right);
+        self.border_radius_default = (left, right);
+        self.eye_l.corner_radii = None;
+        self.eye_r.corner_radii = None;
+        self
+    }
+
+    /// Set a separate radius for each corner of one eye
+    ///
+    /// For stylized shapes such as eyes rounded only at the top. Each
+    /// radius is limited to half of the eye's size when drawn.
+    pub fn set_corner_radii(
+        &mut self,
+        eye: Side,
+        top_left: u32,
+        top_right: u32,
+        bottom_left: u32,
+        bottom_right: u32,
+    ) -> &mut Self {
+        let geometry = match eye {
+            Side::Left => &mut self.eye_l,
+            Side::Right => &mut self.eye_r,
+        };
+        geometry.corner_radii = Some(CornerRadii {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+        });
+        self
+    }
+
+    /// Set the pixel aspect ratio of the display (pixel width / height)
+    ///
+    /// `1.0` (the default) is for square pixels. On a display whose pixels
+    /// are twice as wide as tall use `2.0`: the vertical corner radius is
+    /// scaled by the ratio so rounded corners look round on the hardware.
+    /// Non-positive values are ignored.
+    pub fn set_pixel_aspect(&mut self, ratio: f32) -> &mut Self {
+        if ratio > 0.0 {
+            self.pixel_aspect = ratio;
+        }
+        self
     }
 
     /// Set space between the eyes
-    pub fn set_space_between(&mut self, space: u32) {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BotEyesError::SpacingTooLarge`] if the row of eyes would no
+    /// longer fit on the screen. The spacing is left unchanged on error; use
+    /// [`set_space_between_unchecked()`](Self::set_space_between_unchecked)
+    /// to skip validation.
+    pub fn set_space_between(&mut self, space: u32) -> Result<(), BotEyesError> {
+        if space > self.screen_width
+            || self.row_width(self.eye_l.width, space) > self.screen_width as u64
+        {
+            return Err(BotEyesError::SpacingTooLarge { space });
+        }
+        self.set_space_between_unchecked(space);
+        Ok(())
+    }
+
+    /// Set space between the eyes without validation
+    pub fn set_space_between_unchecked(&mut self, space: u32) -> &mut Self {
         self.space_between = space;
         self.space_between_next = space;
+        self
     }
 
     /// Set eye gaze direction
-    pub fn set_position(&mut self, position: Position) {
-        let constraints = ScreenConstraints::new(self.screen_width, self.screen_height);
-        let max_x = constraints.max_x(self.eye_l.width, self.space_between, self.eye_r.width);
-        let max_y = constraints.max_y(self.eye_l.height);
+    pub fn set_position(&mut self, position: Position) -> &mut Self {
+        let (x, y) = self.position_target(position);
+        self.set_gaze_target(x, y);
+        self
+    }
+
+    /// Left eye coordinates for a compass position
+    fn position_target(&self, position: Position) -> (i32, i32) {
+        let (min_x, max_x) = self.gaze_range_x();
+        let (min_y, max_y) = self.gaze_range_y();
+        let mid_x = min_x + half_rounded(max_x - min_x);
+        let mid_y = min_y + half_rounded(max_y - min_y);
 
         match position {
-            Position::North => {
-                self.eye_l_x_next = max_x / 2;
-                self.eye_l_y_next = 0;
-            }
-            Position::NorthEast => {
-                self.eye_l_x_next = max_x;
-                self.eye_l_y_next = 0;
-            }
-            Position::East => {
-                self.eye_l_x_next = max_x;
-                self.eye_l_y_next = max_y / 2;
-            }
-            Position::SouthEast => {
-                self.eye_l_x_next = max_x;
-                self.eye_l_y_next = max_y;
-            }
-            Position::South => {
-                self.eye_l_x_next = max_x / 2;
-                self.eye_l_y_next = max_y;
-            }
-            Position::SouthWest => {
-                self.eye_l_x_next = 0;
-                self.eye_l_y_next = max_y;
-            }
-            Position::West => {
-                self.eye_l_x_next = 0;
-                self.eye_l_y_next = max_y / 2;
-            }
-            Position::NorthWest => {
-                self.eye_l_x_next = 0;
-                self.eye_l_y_next = 0;
-            }
-            Position::Center => {
-                self.eye_l_x_next = max_x / 2;
-                self.eye_l_y_next = max_y / 2;
-            }
+            Position::North => (mid_x, min_y),
+            Position::NorthEast => (max_x, min_y),
+            Position::East => (max_x, mid_y),
+            Position::SouthEast => (max_x, max_y),
+            Position::South => (mid_x, max_y),
+            Position::SouthWest => (min_x, max_y),
+            Position::West => (min_x, mid_y),
+            Position::NorthWest => (min_x, min_y),
+            Position::Center => (mid_x, mid_y),
         }
     }
 
-    // =====================================================================
-    // Mode Setters
-    // =====================================================================
-
-    /// Enable or disable cyclops mode (single eye)
-    pub fn set_cyclops(&mut self, enabled: bool) {
-        self.cyclops = enabled;
+    /// Look toward a normalized `(x, y)` point
+    ///
+    /// Uses the same coordinates as [`gaze()`](Self::gaze): `(0.0, 0.0)`
+    /// is center, `(1.0, 0.0)` far East, `(0.0, -1.0)` far North. Values
+    /// are clamped to `-1.0..=1.0`. Handy for following a face or a
+    /// sensor; see [`set_gaze_deadzone()`](Self::set_gaze_deadzone) for
+    /// noisy input.
+    pub fn look_at(&mut self, x: f32, y: f32) -> &mut Self {
+        let target = |pos: f32, (min, max): (i32, i32)| {
+            let half = (max - min) as f32 / 2.0;
+            (min as f32 + half + pos.clamp(-1.0, 1.0) * half).round() as i32
+        };
+        let x = target(x, self.gaze_range_x());
+        let y = target(y, self.gaze_range_y());
+        self.set_gaze_target(x, y);
+        self
     }
 
-    /// Enable or disable curious mode
-    pub fn set_curiosity(&mut self, enabled: bool) {
-        self.curious = enabled;
+    /// Set the gaze anywhere between the compass positions
+    ///
+    /// `fx`/`fy` in `-1.0..=1.0` place the eyes proportionally within the
+    /// movement box: `(0.0, 0.0)` is [`Center`](Position::Center),
+    /// `(1.0, 0.0)` [`East`](Position::East), `(0.5, 0.0)` halfway between.
+    /// The inverse of [`gaze()`](Self::gaze); same as [`look_at()`](Self::look_at).
+    pub fn set_position_fraction(&mut self, fx: f32, fy: f32) -> &mut Self {
+        self.look_at(fx, fy)
     }
 
-    /// Enable or disable sweat animation
-    pub fn set_sweat(&mut self, enabled: bool) {
-        self.sweat = enabled;
+    /// Keep the eyes at least `margin` pixels from the screen edges (default 0)
+    ///
+    /// Shrinks the box the eyes move in for [`set_position()`](Self::set_position),
+    /// [`look_at()`](Self::look_at), [`follow_path()`](Self::follow_path) and
+    /// idle movement, e.g. for displays with a bezel over the outer pixels.
+    /// [`gaze()`](Self::gaze) reports positions within the smaller box. A
+    /// margin larger than the room available centres the eyes on that axis.
+    pub fn set_gaze_margin(&mut self, margin: u32) -> &mut Self {
+        self.gaze_margin = margin;
+        self
     }
 
-    /// Check if cyclops mode is enabled
-    pub fn is_cyclops(&self) -> bool {
-        self.cyclops
+    /// Horizontal range of gaze target positions, inset by the margin
+    fn gaze_range_x(&self) -> (i32, i32) {
+        self.gaze_range(self.get_constraint_x())
     }
 
-    /// Check if sweat animation is enabled
-    pub fn has_sweat(&self) -> bool {
-        self.sweat
+    /// Vertical range of gaze target positions, inset by the margin
+    fn gaze_range_y(&self) -> (i32, i32) {
+        self.gaze_range(self.get_constraint_y())
     }
 
-    // =====================================================================
-    // Animation Control
-    // =====================================================================
-
-    /// Open both eyes
-    pub fn open(&mut self) {
-        self.eye_l_open = true;
-        self.eye_r_open = true;
+    fn gaze_range(&self, max: i32) -> (i32, i32) {
+        let max = max.max(0);
+        let margin = (self.gaze_margin.min(i32::MAX as u32) as i32).min(max / 2);
+        (margin, max - margin)
     }
 
-    /// Close both eyes
-    pub fn close(&mut self) {
-        self.eye_l_height_next = 1;
-        self.eye_r_height_next = 1;
-        self.eye_l_open = false;
-        self.eye_r_open = false;
+    /// Ignore gaze targets within `px` pixels of the current one (default 0)
+    ///
+    /// Applies to [`set_position()`](Self::set_position) and
+    /// [`look_at()`](Self::look_at), so small changes in noisy input don't
+    /// keep the eyes twitching. Idle movement and animations are not
+    /// affected.
+    pub fn set_gaze_deadzone(&mut self, px: u32) -> &mut Self {
+        self.gaze_deadzone = px;
+        self
     }
 
-    /// Blink both eyes
-    pub fn blink(&mut self) {
-        self.close();
-        self.open();
+    /// Move the gaze target unless it's within the deadzone
+    fn set_gaze_target(&mut self, x: i32, y: i32) {
+        let dx = (x - self.eye_l_x_next) as i64;
+        let dy = (y - self.eye_l_y_next) as i64;
+        let deadzone = self.gaze_deadzone as i64;
+        if dx * dx + dy * dy <= deadzone * deadzone {
+            return;
+        }
+        self.eye_l_x_next = x;
+        self.eye_l_y_next = y;
     }
 
-    /// Open or close specific eyes
-    pub fn blink_eyes(&mut self, left: bool, right: bool) {
-        if left {
-            self.eye_l_height_next = 1;
-            self.eye_l_open = false;
-        }
-        if right {
-            self.eye_r_height_next = 1;
-            self.eye_r_open = false;
-        }
-        self.open_eyes(left, right);
+    /// Shift the whole eye cluster off-centre by (`dx`, `dy`) pixels
+    ///
+    /// Applied on top of the gaze position and moves all eyes together,
+    /// e.g. to place the face on the left of a wide panel. Gaze directions,
+    /// idle movement and [`gaze()`](Self::gaze) still work within the
+    /// shifted box. The eyes ease to the new offset like any position
+    /// change. The offset is limited so some part of the eyes stays on
+    /// screen.
+    pub fn set_cluster_offset(&mut self, dx: i32, dy: i32) -> &mut Self {
+        self.cluster_offset = (dx, dy);
+        self
     }
 
-    /// Open specific eyes
-    pub fn open_eyes(&mut self, left: bool, right: bool) {
-        if left {
-            self.eye_l_open = true;
-        }
-        if right {
-            self.eye_r_open = true;
-        }
+    /// Set eye gaze direction, jumping there without easing
+    ///
+    /// Useful on startup or after a reset, so the first frame doesn't show
+    /// the eyes sliding in from their previous position.
+    pub fn set_position_instant(&mut self, position: Position) -> &mut Self {
+        self.set_position(position);
+        self.snap_position();
+        self
     }
 
-    /// Start confused animation
-    pub fn anim_confused(&mut self) {
-        self.confused = true;
-        self.confused_toggle = true;
+    /// Move the eyes to the centre immediately
+    pub fn center_instant(&mut self) -> &mut Self {
+        self.set_position_instant(Position::Center)
     }
 
-    /// Start laugh animation
-    pub fn anim_laugh(&mut self) {
-        self.laugh = true;
-        self.laugh_toggle = true;
+    /// Jump both eyes to their target positions
+    fn snap_position(&mut self) {
+        let (dx, dy) = self.cluster_offset_clamped();
+        self.eye_l_x = self.eye_l_x_next + dx;
+        self.eye_l_y = self.eye_l_y_next + dy;
+        self.eye_r_x_next = self.right_eye_x(self.eye_l_x_next);
+        self.eye_r_y_next = self.eye_l_y_next;
+        self.eye_r_x = self.eye_r_x_next + dx;
+        self.eye_r_y = self.eye_r_y_next + dy;
     }
 
     // =====================================================================
-    // Auto Animation Setters
+    // Mode Setters
     // =====================================================================
 
-    /// Enable or disable automatic blinking
-    pub fn set_autoblinker(&mut self, enabled: bool, interval: u64, variation: u64) {
-        self.autoblinker = enabled;
-        self.blink_config.interval = interval;
-        self.blink_config.variation = variation;
+    /// Enable or disable cyclops mode (single eye)
+    ///
+    /// The other eye collapses (see [`set_cyclops_eye()`](Self::set_cyclops_eye))
+    /// and the single eye moves so the gaze direction is kept, ending up
+    /// horizontally centred when looking straight ahead.
+    pub fn set_cyclops(&mut self, enabled: bool) -> &mut Self {
+        let old_range = self.get_constraint_x();
+        self.cyclops = enabled;
+        if enabled {
+            self.eye_count = 1;
+        } else if self.eye_count == 1 {
+            self.eye_count = 2;
+        }
+
+        // Same relative gaze within the new (wider or narrower) range
+        let new_range = self.get_constraint_x().max(0);
+        self.eye_l_x_next = if old_range > 0 {
+            (self.eye_l_x_next.clamp(0, old_range) as i64 * new_range as i64 / old_range as i64)
+                as i32
+        } else {
+            half_rounded(new_range)
+        };
+        self
     }
 
-    /// Enable or disable idle mode
+    /// Choose which eye remains in cyclops mode (default left)
     ///
-    /// # Arguments
+    /// The single eye keeps that eye's shape (corner radii, height), and
+    /// the other one collapses during the transition.
+    pub fn set_cyclops_eye(&mut self, side: Side) -> &mut Self {
+        self.cyclops_eye = side;
+        self
+    }
+
+    /// Set the number of eyes in the row (default 2)
     ///
-    /// * `enabled` - Enable/disable idle animation
-    /// * `interval` - Base time between eye movements in seconds
-    /// * `variation` - Random variation added to interval in seconds
-    /// * `x_range` - X-axis movement range as percentage (0-100), default 100
-    /// * `y_range` - Y-axis movement range as percentage (0-100), default 100
-    pub fn set_idle_mode(
-        &mut self,
-        enabled: bool,
-        interval: u64,
-        variation: u64,
-        x_range: u32,
-        y_range: u32,
-    ) {
-        self.idle = enabled;
-        self.idle_config.interval = interval;
-        self.idle_config.variation = variation;
-        self.idle_config.x_range = x_range.min(100);
-        self.idle_config.y_range = y_range.min(100);
+    /// `1` is the same as cyclops mode. With three or more eyes the extra
+    /// eyes sit evenly spaced between the left and right eye, sharing the
+    /// left eye's size and the spacing set with
+    /// [`set_space_between()`](Self::set_space_between). Zero is treated as 1.
+    /// The row is re-centred horizontally.
+    pub fn set_eye_count(&mut self, count: u8) -> &mut Self {
+        self.eye_count = count.max(1);
+        self.cyclops = self.eye_count == 1;
+        self.eye_l_x_next = half_rounded(self.get_constraint_x().max(0));
+        self
     }
 
-    /// Enable or disable horizontal flicker (shaking)
-    pub fn set_h_flicker(&mut self, enabled: bool, amplitude: u32) {
-        self.h_flicker = enabled;
-        self.h_flicker_amplitude = amplitude;
+    /// Enable or disable pupils
+    ///
+    /// Draws a round pupil in each eye that follows the gaze direction.
+    /// Pupils widen for Happy and Surprise and narrow for Angry; see
+    /// [`set_pupil_dilation()`](Self::set_pupil_dilation).
+    pub fn set_pupils(&mut self, enabled: bool) -> &mut Self {
+        self.pupils = enabled;
+        self
     }
 
-    /// Enable or disable vertical flicker (shaking)
-    pub fn set_v_flicker(&mut self, enabled: bool, amplitude: u32) {
-        self.v_flicker = enabled;
-        self.v_flicker_amplitude = amplitude;
+    /// Set the base pupil size, 0.0-1.0 of the largest pupil that fits
+    ///
+    /// Moods scale this up or down (default 0.5); the pupil always stays
+    /// inside the eye.
+    pub fn set_pupil_dilation(&mut self, ratio: f32) -> &mut Self {
+        self.pupil_dilation = ratio.clamp(0.0, 1.0);
+        self
     }
 
-    // =====================================================================
-    // Drawing
-    // =====================================================================
+    /// Enable or disable an iris ring around the pupil
+    ///
+    /// Draws a disc of radius `outer` in `color` with a background-colored
+    /// hole of radius `inner`, following the gaze like the
+    /// [pupils](Self::set_pupils). With pupils also enabled, the
+    /// mood-dilated pupil replaces the fixed hole. Radii shrink as needed
+    /// so the iris stays inside the eye and keeps at least a 1px ring.
+    pub fn set_iris(&mut self, enabled: bool, inner: u32, outer: u32, color: u8) -> &mut Self {
+        self.iris = enabled.then_some((inner, outer, color));
+        self
+    }
 
-    /// Draw a frame of the robot eyes animation
+    /// Enable or disable curious mode
+    pub fn set_curiosity(&mut self, enabled: bool) -> &mut Self {
+        self.curious = enabled;
+        self
+    }
+
+    /// Set how many pixels an eye grows in curious mode (default 8)
+    pub fn set_curiosity_amount(&mut self, amount: u32) -> &mut Self {
+        self.curious_amount = amount;
+        self
+    }
+
+    /// Set how close (in pixels) an eye must get to the screen edge before
+    /// it grows in curious mode (default 10)
+    pub fn set_curiosity_threshold(&mut self, threshold: u32) -> &mut Self {
+        self.curious_threshold = threshold as i32;
+        self
+    }
+
+    /// Enable or disable sweat animation
+    pub fn set_sweat(&mut self, enabled: bool) -> &mut Self {
+        self.sweat = enabled;
+        self
+    }
+
+    /// Mirror the rendered output left-to-right
     ///
-    /// This is the main rendering function. Call this each frame
-    /// with the current timestamp to update animations.
-    /// Draw eyes to an existing image buffer
+    /// Applied to the finished frame, so asymmetric effects (sweat, one-eye
+    /// moods) are mirrored too. Combine with [`set_flip_vertical()`](Self::set_flip_vertical)
+    /// for a 180° rotation (e.g. ceiling-mounted panels).
+    pub fn set_flip_horizontal(&mut self, enabled: bool) -> &mut Self {
+        self.flip_horizontal = enabled;
+        self
+    }
+
+    /// Mirror the rendered output top-to-bottom
+    pub fn set_flip_vertical(&mut self, enabled: bool) -> &mut Self {
+        self.flip_vertical = enabled;
+        self
+    }
+
+    /// Rotate the rendered output clockwise
     ///
-    /// Modifies the given buffer in place instead of creating a new one.
-    /// This is more efficient for animation loops.
+    /// `screen_width`/`screen_height` always describe the unrotated canvas
+    /// the eyes are laid out on. For 90°/270° the output image has width and
+    /// height swapped: [`draw_eyes()`](Self::draw_eyes) returns the rotated size
+    /// and buffers passed to [`draw_into()`](Self::draw_into) must match
+    /// [`output_size()`](Self::output_size). Flips are applied before rotation.
+    pub fn set_rotation(&mut self, rotation: Rotation) -> &mut Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Set foreground (eye) and background colors as grayscale levels
     ///
-    /// # Arguments
+    /// Defaults to white eyes (255) on black (0). Eyelid overlays cut out
+    /// using the background color.
+    pub fn set_colors(&mut self, fg: u8, bg: u8) -> &mut Self {
+        self.fg_color = fg;
+        self.bg_color = bg;
+        self
+    }
+
+    /// Swap foreground and background (e.g. dark eyes on a light screen)
     ///
-    /// * `img` - Mutable reference to the image buffer (must match screen dimensions)
-    /// * `current_time` - Current timestamp in milliseconds
+    /// Applies to everything drawn, including eyelid cut-outs, so moods
+    /// keep their shape. A custom sweat color is left as is.
+    pub fn set_inverted(&mut self, inverted: bool) -> &mut Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Enable or disable anti-aliasing
     ///
-    /// # Example
+    /// Shapes are drawn at 4x resolution and averaged, so edge pixels get
+    /// gray levels between the background and foreground colors.
+    pub fn set_antialias(&mut self, enabled: bool) -> &mut Self {
+        self.antialias = enabled;
+        self
+    }
+
+    /// Set the colors used by [`draw_into_rgb()`](Self::draw_into_rgb)
     ///
-    /// ```rust
-    /// use boteyes::RoboEyes;
-    /// let mut eyes = RoboEyes::new(128, 64);
-    /// let mut buffer = image::GrayImage::new(128, 64);
+    /// Each pixel's coverage (0 at the background gray level, 1 at the
+    /// foreground) picks an evenly spaced palette entry, so with
+    /// anti-aliasing or multi-level effects a palette such as
+    /// `[black, dark red, orange, white]` gives soft glowing edges. The
+    /// default is the two-entry bg/fg gray palette. An empty palette
+    /// restores the default.
+    pub fn set_palette(&mut self, palette: Vec<Rgb<u8>>) -> &mut Self {
+        self.palette = if palette.is_empty() {
+            None
+        } else {
+            Some(palette)
+        };
+        self
+    }
+
+    /// Fill the eyes with a vertical gradient in [`draw_into_rgb()`](Self::draw_into_rgb)
     ///
-    /// loop {
-    ///     eyes.draw_into(&mut buffer, 1000);
-    ///     // use buffer...
-    /// }
-    /// ```
-    pub fn draw_into(&mut self, img: &mut GrayImage, current_time: u64) {
-        self.current_time = current_time;
+    /// Each eye goes from `top` on its first row to `bottom` on its last,
+    /// replacing the palette's foreground color inside the eyes. Eyelids,
+    /// pupils and everything outside the eyes keep the palette colors.
+    /// Grayscale output is unaffected.
+    pub fn set_eye_gradient(&mut self, top: Rgb<u8>, bottom: Rgb<u8>) -> &mut Self {
+        self.eye_gradient = Some((top, bottom));
+        self
+    }
 
-        // Clear buffer
-        img.pixels_mut().for_each(|p| *p = image::Luma([BGCOLOR]));
+    /// Light pixels above `split_row` with `top` and the rest with `bottom`
+    ///
+    /// Mirrors two-tone OLEDs such as the yellow/blue 128x64 panels whose
+    /// first 16 rows are a different color. Rows are counted in the output
+    /// image, after rotation and flips. Partly lit pixels keep their
+    /// coverage, and the background is left alone.
+    pub fn set_split_color(&mut self, split_row: u32, top: u8, bottom: u8) -> &mut Self {
+        self.split_color = Some((split_row, top, bottom));
+        self
+    }
 
-        // 1. Pre-calculation: Tween values
-        self.update_curious_mode();
-        self.update_eye_heights();
+    /// Enable or disable a drop shadow under the eyes
+    ///
+    /// A copy of each eye is drawn `offset` pixels down and right, beneath
+    /// the eyes, in a color `intensity`/255 of the way from the background
+    /// to the foreground. Not drawn by [`draw_packed()`](Self::draw_packed),
+    /// where it would turn into solid pixels.
+    pub fn set_shadow(&mut self, enabled: bool, offset: i32, intensity: u8) -> &mut Self {
+        self.shadow = enabled;
+        self.shadow_offset = offset;
+        self.shadow_intensity = intensity;
+        self
+    }
 
-        // Tween heights
-        self.eye_l_height_current = (self.eye_l_height_current as f32
-            + self.eye_l_height_next as f32
-            + self.eye_l_height_offset as f32) as u32
-            / 2;
-        self.eye_r_height_current = (self.eye_r_height_current as f32
-            + self.eye_r_height_next as f32
-            + self.eye_r_height_offset as f32) as u32
-            / 2;
+    /// Set the gray level at which [`draw_packed()`](Self::draw_packed) lights a pixel
+    ///
+    /// Pixels at or above `threshold` are set (default 128). Lower it when
+    /// the foreground color is dim, or tune it to decide how anti-aliased
+    /// edges round off.
+    pub fn set_binary_threshold(&mut self, threshold: u8) -> &mut Self {
+        self.binary_threshold = threshold;
+        self
+    }
 
-        if self.eye_l_open && self.eye_l_height_current <= 1 + self.eye_l_height_offset {
-            self.eye_l_height_next = self.eye_l_height_default;
-        }
-        if self.eye_r_open && self.eye_r_height_current <= 1 + self.eye_r_height_offset {
-            self.eye_r_height_next = self.eye_r_height_default;
-        }
+    /// Set sweat drop color, overriding the foreground color
+    pub fn set_sweat_color(&mut self, color: u8) -> &mut Self {
+        self.sweat_color = Some(color);
+        self
+    }
 
-        self.space_between =
-            (self.space_between as f32 + self.space_between_next as f32) as u32 / 2;
-        self.tween_positions();
+    /// Set sweat drop corner radius (default 3)
+    pub fn set_sweat_radius(&mut self, radius: u32) -> &mut Self {
+        self.sweat_radius = radius;
+        self
+    }
 
-        self.eye_l.border_radius =
-            (self.eye_l.border_radius as f32 + self.eye_l.border_radius as f32) as u32 / 2;
-        self.eye_r.border_radius =
-            (self.eye_r.border_radius as f32 + self.eye_r.border_radius as f32) as u32 / 2;
+    /// Set the number of sweat drops spread across the forehead (default 3)
+    pub fn set_sweat_count(&mut self, count: usize) -> &mut Self {
+        self.sweat_drops = SweatDrops::new(self.screen_width, self.sweat_region, count);
+        self
+    }
 
-        // 2. Animation processing
-        self.process_autoblinker();
-        self.process_laugh();
-        self.process_confused();
-        self.process_idle();
-        self.apply_flicker();
+    /// Set how fast sweat drops start falling, in pixels per second (default 31.25)
+    ///
+    /// Drops move by elapsed time, so the speed doesn't depend on the frame
+    /// rate. Negative values are treated as 0.
+    pub fn set_sweat_speed(&mut self, px_per_sec: f32) -> &mut Self {
+        self.sweat_speed = px_per_sec.max(0.0) / 1000.0;
+        self
+    }
 
-        if self.cyclops {
-            self.eye_r_height_current = 0;
-            self.eye_r.width = 0;
-            self.space_between = 0;
-        }
-
-        // 3. Shape drawing
-        // Apply eye scale for Confuse mood
-        let l_w = (self.eye_l.width as f32 * self.eye_l_scale) as u32;
-        let l_h = (self.eye_l_height_current as f32 * self.eye_l_scale) as u32;
-        let l_off = ((l_w as i32) - (self.eye_l.width as i32)) / 2;
-        draw_rounded_rect(
-            img,
+    /// Set how fast sweat drops speed up as they fall, in pixels per second squared (default 30)
+    ///
+    /// 0 keeps drops at the [fall speed](Self::set_sweat_speed). Negative
+    /// values are treated as 0.
+    pub fn set_sweat_gravity(&mut self, px_per_sec2: f32) -> &mut Self {
+        self.sweat_gravity = px_per_sec2.max(0.0) / 1_000_000.0;
+        self
+    }
+
+    /// Set the band sweat drops fall through
+    ///
+    /// Drops spawn at `top` and fall between half and all of `height`
+    /// pixels. Defaults to a band near the top of the screen scaled to the
+    /// screen height.
+    pub fn set_sweat_region(&mut self, top: u32, height: u32) -> &mut Self {
+        self.sweat_region = SweatRegion { top, height };
+        self.sweat_drops = SweatDrops::new(
             self.screen_width,
-            self.screen_height,
-            self.eye_l_x - l_off,
-            self.eye_l_y,
-            l_w,
-            l_h,
-            self.eye_l.border_radius,
-            MAINCOLOR,
+            self.sweat_region,
+            self.sweat_drops.0.len(),
         );
+        self
+    }
 
-        if !self.cyclops {
-            let r_w = (self.eye_r.width as f32 * self.eye_r_scale) as u32;
-            let r_h = (self.eye_r_height_current as f32 * self.eye_r_scale) as u32;
-            let r_off = ((r_w as i32) - (self.eye_r.width as i32)) / 2;
-            draw_rounded_rect(
-                img,
-                self.screen_width,
-                self.screen_height,
-                self.eye_r_x - r_off,
-                self.eye_r_y,
-                r_w,
-                r_h,
-                self.eye_r.border_radius,
-                MAINCOLOR,
-            );
-        }
+    /// Enable or disable the glitch effect
+    ///
+    /// Each frame, random scanlines are shifted sideways and some pixels are
+    /// inverted, for a "malfunctioning robot" look. `intensity` (0-255)
+    /// scales the number of affected rows, the shift distance and the
+    /// number of flipped pixels.
+    pub fn set_glitch(&mut self, enabled: bool, intensity: u8) -> &mut Self {
+        self.glitch = enabled;
+        self.glitch_intensity = intensity;
+        self
+    }
 
-        // 4. Mood overlays
-        self.update_mood_transitions();
-        self.draw_eyelids(img);
+    /// Enable or disable eyebrows
+    ///
+    /// Draws a short line above each eye (a single one in cyclops mode)
+    /// tilted according to the mood: angry brows slope down towards the
+    /// centre, sad brows slope up and surprised brows are raised.
+    pub fn set_eyebrows(&mut self, enabled: bool) -> &mut Self {
+        self.eyebrows = enabled;
+        self
+    }
 
-        // 5. Loading animation (blue flickering)
-        if self.mood == Mood::Loading {
-            self.draw_loading(img);
-        }
+    /// Enable or disable scanner mode
+    ///
+    /// Replaces the eyes with a bright vertical bar sweeping back and forth
+    /// across the screen with a fading trail, e.g. for boot screens.
+    pub fn set_scanner(&mut self, enabled: bool) -> &mut Self {
+        self.scanner = enabled;
+        self
+    }
 
-        // 6. Sweat animation
-        if self.sweat {
-            self.draw_sweat(img);
-        }
+    /// Enable or disable the "thinking" spinner
+    ///
+    /// A small dot orbits above the centre of the eyes, drawn on top of the
+    /// normal eyes.
+    pub fn set_thinking(&mut self, enabled: bool) -> &mut Self {
+        self.thinking = enabled;
+        self
     }
 
-    /// Draw a frame of the robot eyes animation
+    /// Set the time for one orbit of the thinking dot in milliseconds (default 1000)
     ///
-    /// Creates a new image buffer each call. For animation loops, use [`draw_into()`]
-    /// instead to avoid allocating a new buffer every frame.
+    /// Zero is treated as 1.
+    pub fn set_thinking_speed(&mut self, period_ms: u64) -> &mut Self {
+        self.thinking_period = period_ms.max(1);
+        self
+    }
+
+    /// Show a short status word (e.g. "HI") centred along the bottom edge
     ///
-    /// # Arguments
+    /// Drawn every frame in the foreground color with the built-in 3x5
+    /// font (see [`draw_text()`]), so lowercase shows as uppercase. Text
+    /// wider than the screen scrolls right to left. An empty string
+    /// removes the status line.
     ///
-    /// * `current_time` - Current timestamp in milliseconds
+    /// While text is shown the bottom rows are reserved for it, so gaze
+    /// targets stay above the status line.
+    pub fn set_status_text(&mut self, text: &str) -> &mut Self {
+        self.status_text = text.to_ascii_uppercase();
+        self.eye_l_y_next = self.eye_l_y_next.min(self.get_constraint_y().max(0));
+        self
+    }
+
+    /// Check if cyclops mode is enabled
+    pub fn is_cyclops(&self) -> bool {
+        self.cyclops
+    }
+
+    /// Check if sweat animation is enabled
+    pub fn has_sweat(&self) -> bool {
+        self.sweat
+    }
+
+    /// Key tweened values packed into a fixed array, for cheap logging
     ///
-    /// # Returns
+    /// Meant for printing over a serial port on targets where `Debug`
+    /// formatting is too heavy. The layout is:
     ///
-    /// A new grayscale image buffer.
-    pub fn draw_eyes(&mut self, current_time: u64) -> GrayImage {
-        let mut img = GrayImage::new(self.screen_width, self.screen_height);
-        self.draw_into(&mut img, current_time);
-        img
+    /// | Index | Value |
+    /// |-------|-------|
+    /// | 0, 1 | Left eye x, y |
+    /// | 2, 3 | Right eye x, y |
+    /// | 4, 5 | Left and right eye heights |
+    /// | 6 | Space between the eyes |
+    /// | 7 | Mood, in [`Mood`] declaration order |
+    /// | 8 | Cyclops progress, 0-1000 |
+    /// | 9 | Flags, see below |
+    ///
+    /// Flag bits, from bit 0: left eye open, right eye open, cyclops,
+    /// curious, sweat, autoblinker, idle, horizontal flicker, vertical
+    /// flicker, confused, laugh, paused.
+    pub fn debug_state(&self) -> [i32; 10] {
+        let flags = [
+            self.eye_l_open,
+            self.eye_r_open,
+            self.cyclops,
+            self.curious,
+            self.sweat,
+            self.autoblinker,
+            self.idle,
+            self.h_flicker,
+            self.v_flicker,
+            self.confused,
+            self.laugh,
+            self.paused,
+        ]
+        .iter()
+        .enumerate()
+        .fold(0, |bits, (bit, &set)| bits | (set as i32) << bit);
+
+        [
+            self.eye_l_x,
+            self.eye_l_y,
+            self.eye_r_x,
+            self.eye_r_y,
+            self.eye_l_height_current as i32,
+            self.eye_r_height_current as i32,
+            self.space_between as i32,
+            self.mood as i32,
+            (self.cyclops_progress * 1000.0).round() as i32,
+            flags,
+        ]
+    }
+
+    /// Current gaze direction as a normalized `(x, y)` vector
+    ///
+    /// Derived from the current eye position within the movement box:
+    /// `(0.0, 0.0)` is center, `(1.0, 0.0)` far East, `(0.0, -1.0)` far
+    /// North. Each component is clamped to `-1.0..=1.0`.
+    pub fn gaze(&self) -> (f32, f32) {
+        fn normalize(pos: i32, (min, max): (i32, i32)) -> f32 {
+            let half = (max - min) as f32 / 2.0;
+            if half <= 0.0 {
+                return 0.0;
+            }
+            ((pos as f32 - min as f32 - half) / half).clamp(-1.0, 1.0)
+        }
+
+        let (dx, dy) = self.cluster_offset_clamped();
+        (
+            normalize(self.eye_l_x - dx, self.gaze_range_x()),
+            normalize(self.eye_l_y - dy, self.gaze_range_y()),
+        )
+    }
+
+    /// Rectangles the left and right eyes occupy this frame
+    ///
+    /// Uses the current tweened positions, heights and scales, in screen
+    /// coordinates before rotation and flips. The right eye is `None` once
+    /// it has fully collapsed in cyclops mode; the left rectangle is then
+    /// the single eye, whichever [side](Self::set_cyclops_eye) it came from.
+    pub fn eye_rects(&self) -> (Rect, Option<Rect>) {
+        let scaled = |x: i32, y: i32, width: u32, height: u32, scale: f32| {
+            let w = (width as f32 * scale) as u32;
+            let h = (height as f32 * scale) as u32;
+            let off = (w as i32 - width as i32) / 2;
+            Rect {
+                x: x - off,
+                y,
+                w,
+                h,
+            }
+        };
+
+        let left = scaled(
+            self.eye_l_x,
+            self.eye_l_y,
+            self.eye_l.width,
+            self.eye_l_height_current,
+            self.eye_l_scale,
+        );
+        let right = scaled(
+            self.eye_r_x,
+            self.eye_r_y,
+            self.eye_r.width,
+            self.eye_r_height_current,
+            self.eye_r_scale,
+        );
+        // The collapsing eye shrinks towards its centre during the cyclops
+        // transition
+        let shrink = |mut rect: Rect| {
+            let shown = 1.0 - self.cyclops_progress;
+            let h = (rect.h as f32 * shown) as u32;
+            rect.y += (rect.h - h) as i32 / 2;
+            rect.w = (rect.w as f32 * shown) as u32;
+            rect.h = h;
+            rect
+        };
+
+        match (self.cyclops_eye, self.cyclops_progress < 1.0) {
+            (Side::Left, true) => (left, Some(shrink(right))),
+            (Side::Left, false) => (left, None),
+            (Side::Right, true) => (shrink(left), Some(right)),
+            (Side::Right, false) => (right, None),
+        }
     }
 
     // =====================================================================
-    // Private Helper Methods
+    // Settings Snapshot
     // =====================================================================
 
-    fn update_curious_mode(&mut self) {
-        if self.curious {
-            let left_offset = self.eye_l_x_next <= 10
-                || (self.eye_l_x_next >= self.get_constraint_x() - 10 && self.cyclops);
-            self.eye_l_height_offset = if left_offset { 8 } else { 0 };
+    /// Capture the current user-facing settings
+    ///
+    /// With the `serde` feature enabled the returned [`RoboEyesSettings`]
+    /// can be serialized and later restored via [`apply_settings()`](Self::apply_settings).
+    pub fn settings(&self) -> RoboEyesSettings {
+        RoboEyesSettings {
+            mood: self.mood,
+            eye_width: self.eye_l.width,
+            eye_height: self.eye_l_height_default,
+            border_radius_left: self.border_radius_default.0,
+            border_radius_right: self.border_radius_default.1,
+            space_between: self.space_between_next,
+            cyclops: self.cyclops,
+            curious: self.curious,
+            sweat: self.sweat,
+            autoblinker: self.autoblinker,
+            blink: self.blink_config.clone(),
+            idle: self.idle,
+            idle_config: self.idle_config.clone(),
+            h_flicker: self.h_flicker,
+            h_flicker_amplitude: self.h_flicker_amplitude,
+            v_flicker: self.v_flicker,
+            v_flicker_amplitude: self.v_flicker_amplitude,
+            fg_color: self.fg_color,
+            bg_color: self.bg_color,
+        }
+    }
 
-            let right_offset =
-                self.eye_r_x_next >= self.screen_width as i32 - self.eye_r.width as i32 - 10;
-            self.eye_r_height_offset = if right_offset { 8 } else { 0 };
-        } else {
-            self.eye_l_height_offset = 0;
-            self.eye_r_height_offset = 0;
+    /// Restore settings previously captured with [`settings()`](Self::settings)
+    pub fn apply_settings(&mut self, settings: &RoboEyesSettings) {
+        self.set_mood(settings.mood);
+        self.set_size_unchecked(settings.eye_width, settings.eye_height);
+        self.set_border_radius(settings.border_radius_left, settings.border_radius_right);
+        self.set_space_between_unchecked(settings.space_between);
+        self.set_cyclops(settings.cyclops);
+        self.set_curiosity(settings.curious);
+        self.set_sweat(settings.sweat);
+        self.autoblinker = settings.autoblinker;
+        self.blink_config = settings.blink.clone();
+        self.idle = settings.idle;
+        self.idle_config = settings.idle_config.clone();
+        self.set_h_flicker(settings.h_flicker, settings.h_flicker_amplitude);
+        self.set_v_flicker(settings.v_flicker, settings.v_flicker_amplitude);
+        self.set_colors(settings.fg_color, settings.bg_color);
+    }
+
+    // =====================================================================
+    // Animation Control
+    // =====================================================================
+
+    /// Open both eyes
+    pub fn open(&mut self) {
+        self.eye_l_open = true;
+        self.eye_r_open = true;
+    }
+
+    /// Close both eyes
+    pub fn close(&mut self) {
+        self.eye_l_height_next = self.closed_height;
+        self.eye_r_height_next = self.closed_height;
+        self.eye_l_open = false;
+        self.eye_r_open = false;
+    }
+
+    /// Blink both eyes
+    pub fn blink(&mut self) {
+        self.close();
+        self.open();
+    }
+
+    /// Squint to `ratio` of the full eye height
+    ///
+    /// Shorthand for [`set_eye_height_ratio()`](Self::set_eye_height_ratio);
+    /// `squint(1.0)` opens the eyes fully again.
+    pub fn squint(&mut self, ratio: f32) {
+        self.set_eye_height_ratio(ratio);
+    }
+
+    /// Set the height of a fully closed eye in pixels
+    ///
+    /// Defaults to 1; a 2-3px bar stays visible on displays where a single
+    /// line vanishes after thresholding or scaling down. Applies to
+    /// [`close()`](Self::close), blinks and the autoblinker. Clamped to at
+    /// least 1.
+    pub fn set_closed_thickness(&mut self, px: u32) -> &mut Self {
+        let px = px.max(1);
+        // Keep already closed eyes shut at the new thickness
+        if !self.eye_l_open && self.eye_l_height_next == self.closed_height {
+            self.eye_l_height_next = px;
         }
+        if !self.eye_r_open && self.eye_r_height_next == self.closed_height {
+            self.eye_r_height_next = px;
+        }
+        self.closed_height = px;
+        self
     }
 
-    fn update_eye_heights(&mut self) {
-        // Update Y position for vertical centering based on height change
-        // When height changes, adjust Y to keep the eye centered vertically
-        self.eye_l_y -= self.eye_l_height_offset as i32 / 2;
-        self.eye_r_y -= self.eye_r_height_offset as i32 / 2;
+    /// Open or close specific eyes
+    pub fn blink_eyes(&mut self, left: bool, right: bool) {
+        if left {
+            self.eye_l_height_next = self.closed_height;
+            self.eye_l_open = false;
+        }
+        if right {
+            self.eye_r_height_next = self.closed_height;
+            self.eye_r_open = false;
+        }
+        self.open_eyes(left, right);
     }
 
-    fn tween_positions(&mut self) {
-        self.eye_l_x = (self.eye_l_x as f32 + self.eye_l_x_next as f32) as i32 / 2;
-        self.eye_l_y = (self.eye_l_y as f32 + self.eye_l_y_next as f32) as i32 / 2;
+    /// Open specific eyes
+    pub fn open_eyes(&mut self, left: bool, right: bool) {
+        if left {
+            self.eye_l_open = true;
+        }
+        if right {
+            self.eye_r_open = true;
+        }
+    }
 
-        self.eye_r_x_next = self.eye_l_x_next + self.eye_l.width as i32 + self.space_between as i32;
-        self.eye_r_y_next = self.eye_l_y_next;
+    /// Start confused animation
+    pub fn anim_confused(&mut self) {
+        self.confused = true;
+        self.confused_toggle = true;
+        self.confused_duration_once = None;
+    }
+
+    /// Start confused animation lasting `duration` milliseconds
+    ///
+    /// Only this run is affected; later [`anim_confused()`](Self::anim_confused)
+    /// calls keep the [configured duration](Self::set_confused_duration).
+    pub fn anim_confused_for(&mut self, duration: u64) {
+        self.anim_confused();
+        self.confused_duration_once = Some(duration);
+    }
+
+    /// Start laugh animation
+    pub fn anim_laugh(&mut self) {
+        self.laugh = true;
+        self.laugh_toggle = true;
+        self.laugh_duration_once = None;
+    }
+
+    /// Start laugh animation lasting `duration` milliseconds
+    ///
+    /// Only this run is affected; later [`anim_laugh()`](Self::anim_laugh)
+    /// calls keep the [configured duration](Self::set_laugh_duration).
+    pub fn anim_laugh_for(&mut self, duration: u64) {
+        self.anim_laugh();
+        self.laugh_duration_once = Some(duration);
+    }
+
+    /// Start shiver animation (cold/scared tremble) lasting `duration_ms`
+    ///
+    /// Small horizontal and vertical flicker on top of the eyes; the
+    /// [flicker settings](Self::set_h_flicker) are left untouched.
+    pub fn anim_shiver(&mut self, duration_ms: u64) {
+        self.shiver = true;
+        self.shiver_toggle = true;
+        self.shiver_duration = duration_ms;
+    }
+
+    /// Append an animation to the queue
+    ///
+    /// Queued animations play one after another: each starts on the first
+    /// frame after the previous one (and any running confused, laugh,
+    /// shiver or blink animation) has finished.
+    pub fn queue_animation(&mut self, anim: Anim) -> &mut Self {
+        self.anim_queue.push_back(anim);
+        self
+    }
+
+    /// Drop all queued animations that haven't started yet
+    pub fn clear_queue(&mut self) {
+        self.anim_queue.clear();
+    }
+
+    /// Number of queued animations that haven't started yet
+    pub fn queue_len(&self) -> usize {
+        self.anim_queue.len()
+    }
+
+    /// Check if any animation is running or queued
+    ///
+    /// When this is false a new gesture won't interrupt anything. See
+    /// [`active_animations()`](Self::active_animations) for which ones.
+    pub fn is_animating(&self) -> bool {
+        !self.active_animations().is_empty()
+    }
+
+    /// Animations currently running or queued
+    pub fn active_animations(&self) -> Vec<AnimKind> {
+        let closing = self.eye_l_height_next == self.closed_height
+            || self.eye_r_height_next == self.closed_height;
+        let resizing = self.eye_l_height_current
+            != self.eye_l_height_next + self.eye_l_height_offset
+            || (!self.cyclops
+                && self.eye_r_height_current != self.eye_r_height_next + self.eye_r_height_offset);
+
+        let mut active = Vec::new();
+        if self.blink_in_progress || closing {
+            active.push(AnimKind::Blink);
+        } else if resizing {
+            active.push(AnimKind::Resize);
+        }
+        for (running, kind) in [
+            (self.confused, AnimKind::Confused),
+            (self.laugh, AnimKind::Laugh),
+            (self.shiver, AnimKind::Shiver),
+            (self.acknowledge, AnimKind::Acknowledge),
+            (self.startle, AnimKind::Startle),
+            (self.wake, AnimKind::Wake),
+            (self.peek, AnimKind::Peek),
+            (self.wince, AnimKind::Wince),
+            (!self.anim_queue.is_empty(), AnimKind::Queued),
+        ] {
+            if running {
+                active.push(kind);
+            }
+        }
+        active
+    }
+
+    /// Set how far animated values move toward their target each frame
+    ///
+    /// `factor` (0.0-1.0) is the fraction of the remaining distance covered
+    /// per ~16ms frame; it is scaled by the actual time between frames so
+    /// animations take the same wall-clock time at any frame rate. When
+    /// unset, values move halfway per frame regardless of frame rate.
+    pub fn set_tween_factor(&mut self, factor: f32) -> &mut Self {
+        self.tween_factor = Some(factor.clamp(0.01, 1.0));
+        self
+    }
+
+    /// Use an easing curve for position and height transitions
+    ///
+    /// Each transition then takes a fixed ~160ms following `easing`,
+    /// instead of moving halfway (or by the [tween factor](Self::set_tween_factor)
+    /// and [gaze speed](Self::set_gaze_speed)) every frame. A
+    /// [blink speed](Self::set_blink_speed) still takes precedence for heights.
+    /// `None` goes back to per-frame tweening.
+    pub fn set_easing(&mut self, easing: Option<Easing>) -> &mut Self {
+        self.easing = easing;
+        // Start the next curve from wherever the values are now
+        self.eye_l_x_eased = EasedTween::default();
+        self.eye_l_y_eased = EasedTween::default();
+        self.eye_l_height_eased = EasedTween::default();
+        self.eye_r_height_eased = EasedTween::default();
+        self
+    }
+
+    /// Set how fast the eyes move toward a new position
+    ///
+    /// Works like [`set_tween_factor()`](Self::set_tween_factor) but only
+    /// for eye positions, so gaze can be quick while blinks stay slow (or
+    /// vice versa). When unset, positions follow the general tween factor.
+    pub fn set_gaze_speed(&mut self, factor: f32) -> &mut Self {
+        self.gaze_factor = Some(factor.clamp(0.01, 1.0));
+        self
+    }
+
+    /// Blink out a pattern, e.g. Morse code
+    ///
+    /// Each step is `(closed, duration_ms)`: the eyes close (or open) and
+    /// stay that way for `duration_ms`. Steps play back to back starting
+    /// with the next frame, replacing any pattern still playing, and the
+    /// eyes open again at the end. The autoblinker waits until it's done.
+    pub fn blink_pattern(&mut self, pattern: &[(bool, u64)]) {
+        self.blink_pattern = pattern.iter().copied().collect();
+        self.blink_pattern_until = None;
+    }
+
+    /// Move the gaze through a series of waypoints
+    ///
+    /// Each waypoint is `(x, y, dwell_ms)`: the left eye's target position
+    /// in pixels within the movement box (as set by
+    /// [`set_position()`](Self::set_position), clamped to it), then how
+    /// long to stay before heading to the next one, travel time included.
+    /// Starts with the next frame and replaces any path still playing; the
+    /// eyes stay at the last waypoint unless [looping](Self::set_path_loop).
+    /// Turn idle mode off, or it will pick its own targets in between.
+    pub fn follow_path(&mut self, waypoints: &[(i32, i32, u64)]) {
+        self.path = waypoints.to_vec();
+        self.path_step = 0;
+        self.path_until = None;
+    }
+
+    /// Restart [`follow_path()`](Self::follow_path) from the first waypoint when it ends
+    pub fn set_path_loop(&mut self, enabled: bool) -> &mut Self {
+        self.path_loop = enabled;
+        self
+    }
+
+    /// Start the acknowledge ("got it") gesture
+    ///
+    /// The eyes blink while briefly dipping south, then return to where
+    /// they were looking.
+    pub fn anim_acknowledge(&mut self) {
+        self.acknowledge = true;
+        self.acknowledge_toggle = true;
+    }
+
+    /// Slide the eyes in from off-screen at the `from` edge to the centre
+    ///
+    /// The eyes jump just past that edge (or corner) and ease to the
+    /// centre at the normal gaze speed; the animation ends once they
+    /// arrive. `Position::Center` just eases to the centre.
+    pub fn anim_peek(&mut self, from: Position) {
+        self.peek = true;
+        self.peek_from = from;
+        self.peek_toggle = true;
+    }
+
+    /// Squeeze the eyes shut for `duration_ms`, as if wincing
+    ///
+    /// Unlike a blink the eyes stay a narrow slit and short wrinkle lines
+    /// converge on their outer corners; then they reopen, or stay shut if
+    /// they were closed before or during the wince. Limited to one minute
+    /// like the other timed animations.
+    pub fn anim_wince(&mut self, duration_ms: u64) {
+        self.wince = true;
+        self.wince_toggle = true;
+        self.wince_duration = duration_ms;
+    }
+
+    /// Start the wake-up sequence
+    ///
+    /// The eyes shut, then slowly open over ~1.5 seconds with a couple of
+    /// sleepy flutters and stay open in the current mood. Runs on its own
+    /// from the frame timestamps; no need to call [`open()`](Self::open).
+    pub fn anim_wake(&mut self) {
+        self.wake = true;
+        self.wake_toggle = true;
+    }
+
+    /// Start the startle reaction
+    ///
+    /// Both eyes jump up and grow for a moment, then settle back to where
+    /// they were looking. Mood eyelids follow the eyes.
+    pub fn anim_startle(&mut self) {
+        self.startle = true;
+        self.startle_toggle = true;
+    }
+
+    /// Set how long a blink takes to close and to reopen, in milliseconds
+    ///
+    /// Eye height then changes at a constant rate based on elapsed time
+    /// (a full-height change takes `close_ms` or `open_ms`), independent of
+    /// frame rate. Applies to manual and automatic blinks. Without this,
+    /// heights move halfway per frame (or per [`set_tween_factor()`](Self::set_tween_factor)).
+    pub fn set_blink_speed(&mut self, close_ms: u64, open_ms: u64) -> &mut Self {
+        self.blink_speed = Some((close_ms, open_ms));
+        self
+    }
+
+    /// Set how fast one eye opens and closes relative to normal
+    ///
+    /// `factor` multiplies the height change per frame (or divides the
+    /// [blink speed](Self::set_blink_speed) durations), so `0.5` makes that
+    /// eye lag behind the other for a lazy, drooping blink. Defaults to
+    /// 1.0; not used with an [easing curve](Self::set_easing).
+    pub fn set_eye_speed(&mut self, eye: Side, factor: f32) -> &mut Self {
+        let factor = factor.clamp(0.01, 2.0);
+        match eye {
+            Side::Left => self.eye_l_speed = factor,
+            Side::Right => self.eye_r_speed = factor,
+        }
+        self
+    }
+
+    /// Set confused animation duration in milliseconds (default 500)
+    ///
+    /// Like every one-shot animation it stops after a minute at most.
+    pub fn set_confused_duration(&mut self, ms: u64) -> &mut Self {
+        self.confused_duration = ms;
+        self
+    }
+
+    /// Set laugh animation duration in milliseconds (default 500)
+    ///
+    /// Like every one-shot animation it stops after a minute at most.
+    pub fn set_laugh_duration(&mut self, ms: u64) -> &mut Self {
+        self.laugh_duration = ms;
+        self
+    }
+
+    /// Freeze the animation
+    ///
+    /// While paused, [`draw_into()`](Self::draw_into) re-renders the last
+    /// computed frame and all timers stop. Time spent paused is skipped on
+    /// [`resume()`](Self::resume), so animations continue where they left off.
+    pub fn pause(&mut self) {
+        if !self.paused {
+            self.paused = true;
+            self.paused_at = Some(self.current_time.wrapping_add(self.time_offset));
+        }
+    }
+
+    /// Resume a paused animation
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Check if the animation is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    // =====================================================================
+    // Events
+    // =====================================================================
+
+    /// Register a callback fired the frame the eyes finish reopening after
+    /// a blink (manual or automatic)
+    ///
+    /// Callbacks run from within [`draw_into()`](Self::draw_into) after the
+    /// frame's state update. Cloned instances start without callbacks.
+    /// Callbacks must be `Send`, so share state through e.g. `Arc` rather
+    /// than `Rc`.
+    pub fn on_blink_complete(&mut self, callback: Box<dyn FnMut() + Send>) {
+        self.on_blink_complete = Callback(Some(callback));
+    }
+
+    /// Register a callback fired whenever idle mode picks a new gaze target
+    pub fn on_idle_move(&mut self, callback: Box<dyn FnMut() + Send>) {
+        self.on_idle_move = Callback(Some(callback));
+    }
+
+    // =====================================================================
+    // Auto Animation Setters
+    // =====================================================================
+
+    /// Enable or disable automatic blinking
+    pub fn set_autoblinker(&mut self, enabled: bool, interval: u64, variation: u64) -> &mut Self {
+        self.autoblinker = enabled;
+        self.blink_config.interval = interval;
+        self.blink_config.variation = variation;
+        self
+    }
+
+    /// Set the chance (0.0-1.0) that an automatic blink is a double blink
+    ///
+    /// The second blink follows shortly after the first. Defaults to 0.0.
+    pub fn set_double_blink_chance(&mut self, chance: f32) -> &mut Self {
+        self.blink_config.double_blink_chance = chance.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Enable or disable idle mode
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Enable/disable idle animation
+    /// * `interval` - Base time between eye movements in seconds
+    /// * `variation` - Random variation added to interval in seconds
+    /// * `x_range` - X-axis movement range as percentage (0-100), default 100
+    /// * `y_range` - Y-axis movement range as percentage (0-100), default 100
+    pub fn set_idle_mode(
+        &mut self,
+        enabled: bool,
+        interval: u64,
+        variation: u64,
+        x_range: u32,
+        y_range: u32,
+    ) -> &mut Self {
+        self.idle = enabled;
+        self.idle_config.interval = interval;
+        self.idle_config.variation = variation;
+        self.idle_config.x_range = x_range.min(100);
+        self.idle_config.y_range = y_range.min(100);
+        self
+    }
+
+    /// Pull idle gaze targets towards the centre
+    ///
+    /// `0.0` (the default) picks targets uniformly within the idle range.
+    /// Higher values apply a power curve so the eyes mostly wander near the
+    /// centre and only rarely reach the edges. Negative values are treated
+    /// as `0.0`.
+    pub fn set_idle_bias(&mut self, bias: f32) -> &mut Self {
+        self.idle_bias = bias.max(0.0);
+        self
+    }
+
+    /// Ease back to the centre between idle moves
+    ///
+    /// The eyes look at each random target for half of the idle interval
+    /// and rest at the centre for the other half, instead of jumping
+    /// directly from one random point to the next.
+    pub fn set_idle_return(&mut self, enabled: bool) -> &mut Self {
+        self.idle_return = enabled;
+        if !enabled {
+            self.idle_return_at = None;
+        }
+        self
+    }
+
+    /// Snap to each idle target instead of easing there
+    ///
+    /// Gives a robotic, instant look-around. Also applies to the return to
+    /// centre set with [`set_idle_return()`](Self::set_idle_return).
+    pub fn set_idle_instant(&mut self, enabled: bool) -> &mut Self {
+        self.idle_instant = enabled;
+        self
+    }
+
+    /// Enable or disable the breathing pulse
+    ///
+    /// Eye height gently oscillates around its default by up to `amplitude`
+    /// pixels with a sine wave of `period_ms`. Breathing pauses while a
+    /// blink, confused or laugh animation is running.
+    pub fn set_breathing(&mut self, enabled: bool, amplitude: u32, period_ms: u64) -> &mut Self {
+        self.breathing = enabled;
+        self.breathing_amplitude = amplitude;
+        self.breathing_period = period_ms;
+        if !enabled {
+            self.restore_open_heights();
+        }
+        self
+    }
+
+    /// Enable or disable the heartbeat pulse
+    ///
+    /// Both eyes briefly grow twice per beat, a strong "lub" followed by a
+    /// weaker "dub", at `bpm` beats per minute. Adds to breathing if both
+    /// are on, and pauses like breathing does.
+    pub fn set_heartbeat(&mut self, enabled: bool, bpm: u32) -> &mut Self {
+        self.heartbeat = enabled;
+        self.heartbeat_bpm = bpm;
+        if !enabled {
+            self.restore_open_heights();
+        }
+        self
+    }
+
+    /// Enable or disable micro-saccades
+    ///
+    /// Every few hundred milliseconds the gaze target is nudged by a random
+    /// offset of up to `amplitude` pixels, so eyes looking at a fixed point
+    /// still jitter slightly. Works alongside idle mode; disabling it
+    /// removes the current offset.
+    pub fn set_saccades(&mut self, enabled: bool, amplitude: u32) -> &mut Self {
+        if !enabled && self.saccades_own_target() {
+            self.eye_l_x_next -= self.saccade_offset.0;
+            self.eye_l_y_next -= self.saccade_offset.1;
+        }
+        self.saccades = enabled;
+        self.saccade_amplitude = amplitude;
+        self.saccade_offset = (0, 0);
+        self
+    }
+
+    /// Enable or disable horizontal flicker (shaking)
+    pub fn set_h_flicker(&mut self, enabled: bool, amplitude: u32) -> &mut Self {
+        self.h_flicker = enabled;
+        self.h_flicker_amplitude = amplitude;
+        self
+    }
+
+    /// Enable or disable vertical flicker (shaking)
+    pub fn set_v_flicker(&mut self, enabled: bool, amplitude: u32) -> &mut Self {
+        self.v_flicker = enabled;
+        self.v_flicker_amplitude = amplitude;
+        self
+    }
+
+    /// Set how close (in pixels) flickering eyes may get to the screen edge
+    ///
+    /// Shaken eyes are pinned inside the screen shrunk by `margin` on every
+    /// side (default 0, i.e. fully on-screen).
+    pub fn set_flicker_margin(&mut self, margin: u32) -> &mut Self {
+        self.flicker_margin = margin;
+        self
+    }
+
+    /// Set horizontal shake amplitude used by the confused animation (default 20)
+    pub fn set_confused_amplitude(&mut self, amplitude: u32) -> &mut Self {
+        self.confused_amplitude = amplitude;
+        self
+    }
+
+    /// Set bounce amplitude used by the laugh animation (default 5)
+    pub fn set_laugh_amplitude(&mut self, amplitude: u32) -> &mut Self {
+        self.laugh_amplitude = amplitude;
+        self
+    }
+
+    /// Set which way the laugh animation bounces (default vertical)
+    ///
+    /// Takes effect from the next laugh.
+    pub fn set_laugh_style(&mut self, style: LaughStyle) -> &mut Self {
+        self.laugh_style = style;
+        self
+    }
+
+    // =====================================================================
+    // Drawing
+    // =====================================================================
+
+    /// Draw a frame of the robot eyes animation
+    ///
+    /// This is the main rendering function. Call this each frame
+    /// with the current timestamp to update animations.
+    /// Draw eyes to an existing image buffer
+    ///
+    /// Modifies the given buffer in place instead of creating a new one.
+    /// This is more efficient for animation loops.
+    ///
+    /// # Arguments
+    ///
+    /// * `img` - Mutable reference to the image buffer (must match screen dimensions)
+    /// * `current_time` - Current timestamp in milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use boteyes::RoboEyes;
+    /// let mut eyes = RoboEyes::new(128, 64);
+    /// let mut buffer = image::GrayImage::new(128, 64);
+    ///
+    /// loop {
+    ///     eyes.draw_into(&mut buffer, 1000);
+    ///     // use buffer...
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `img` doesn't match [`output_size()`](Self::output_size).
+    /// Release builds clip the frame to the buffer instead. Use
+    /// [`try_draw_into()`](Self::try_draw_into) to get an error.
+    pub fn draw_into(&mut self, img: &mut GrayImage, current_time: u64) {
+        debug_assert_eq!(img.dimensions(), self.output_size());
+        self.draw_into_at(img, 0, 0, current_time);
+    }
+
+    /// Draw eyes to an existing image buffer, checking its size
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BotEyesError::BufferSize`] without drawing or advancing the
+    /// animation if `img` doesn't match [`output_size()`](Self::output_size).
+    pub fn try_draw_into(
+        &mut self,
+        img: &mut GrayImage,
+        current_time: u64,
+    ) -> Result<(), BotEyesError> {
+        let expected = self.output_size();
+        if img.dimensions() != expected {
+            return Err(BotEyesError::BufferSize {
+                expected,
+                actual: img.dimensions(),
+            });
+        }
+        self.draw_into(img, current_time);
+        Ok(())
+    }
+
+    /// Draw eyes to an existing image buffer, reporting whether the frame changed
+    ///
+    /// Returns `false` if the rendered frame is identical to the one from
+    /// the previous call, so embedded loops can skip pushing it to the
+    /// display. The first call always returns `true`. Frames are compared
+    /// by hash, so only frames drawn through this method count.
+    pub fn draw_into_checked(&mut self, img: &mut GrayImage, current_time: u64) -> bool {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        self.draw_into(img, current_time);
+        let mut hasher = DefaultHasher::new();
+        img.dimensions().hash(&mut hasher);
+        img.as_raw().hash(&mut hasher);
+        let hash = hasher.finish();
+        self.last_frame_hash.replace(hash) != Some(hash)
+    }
+
+    /// Draw eyes into a region of a larger image
+    ///
+    /// The frame (of [`output_size()`](Self::output_size)) is placed with
+    /// its top-left corner at (`offset_x`, `offset_y`) and clipped to `img`,
+    /// so it may be partly or completely outside. Pixels outside the region
+    /// are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `img` - Target image of any size
+    /// * `offset_x`, `offset_y` - Position of the frame within `img`
+    /// * `current_time` - Current timestamp in milliseconds
+    pub fn draw_into_at(
+        &mut self,
+        img: &mut GrayImage,
+        offset_x: i32,
+        offset_y: i32,
+        current_time: u64,
+    ) {
+        if offset_x == 0 && offset_y == 0 && img.dimensions() == self.output_size() {
+            self.draw_output(img, current_time);
+            return;
+        }
+
+        let (width, height) = self.output_size();
+        let mut frame = GrayImage::new(width, height);
+        self.draw_output(&mut frame, current_time);
+        image::imageops::replace(img, &frame, offset_x as i64, offset_y as i64);
+    }
+
+    /// Draw a rotated frame filling `img`
+    fn draw_output(&mut self, img: &mut GrayImage, current_time: u64) {
+        match self.rotation {
+            Rotation::Deg0 => self.draw_frame(img, current_time),
+            Rotation::Deg180 => {
+                self.draw_frame(img, current_time);
+                image::imageops::rotate180_in_place(img);
+            }
+            Rotation::Deg90 | Rotation::Deg270 => {
+                let mut frame = GrayImage::new(self.screen_width, self.screen_height);
+                self.draw_frame(&mut frame, current_time);
+                let rotated = if self.rotation == Rotation::Deg90 {
+                    image::imageops::rotate90(&frame)
+                } else {
+                    image::imageops::rotate270(&frame)
+                };
+                img.copy_from_slice(rotated.as_raw());
+            }
+        }
+
+        if let Some((split_row, top, bottom)) = self.split_color {
+            self.apply_split_color(img, split_row, top, bottom);
+        }
+    }
+
+    /// Recolor lit pixels by output row, keeping their coverage
+    fn apply_split_color(&self, img: &mut GrayImage, split_row: u32, top: u8, bottom: u8) {
+        let (fg, bg) = (self.fg() as f32, self.bg() as f32);
+        if fg == bg {
+            return;
+        }
+        for (_, y, pixel) in img.enumerate_pixels_mut() {
+            let coverage = ((pixel[0] as f32 - bg) / (fg - bg)).clamp(0.0, 1.0);
+            let target = if y < split_row { top } else { bottom } as f32;
+            pixel[0] = (bg + coverage * (target - bg)).round() as u8;
+        }
+    }
+
+    /// Size of the images produced by [`draw_into()`](Self::draw_into)
+    ///
+    /// Equal to the screen size, with width and height swapped for
+    /// 90°/270° rotation.
+    pub fn output_size(&self) -> (u32, u32) {
+        match self.rotation {
+            Rotation::Deg0 | Rotation::Deg180 => (self.screen_width, self.screen_height),
+            Rotation::Deg90 | Rotation::Deg270 => (self.screen_height, self.screen_width),
+        }
+    }
+
+    /// Draw an unrotated frame in screen coordinates
+    fn draw_frame(&mut self, img: &mut GrayImage, current_time: u64) {
+        self.advance(current_time);
+        if self.antialias {
+            let (width, height) = img.dimensions();
+            let mut canvas = Supersampled::new(width, height, ANTIALIAS_FACTOR);
+            self.render_counted(&mut canvas);
+            canvas.resolve(img);
+        } else {
+            self.render_counted(&mut Raster::new(img));
+        }
+
+        // Frozen frames are re-rendered without new glitch noise
+        if self.glitch && !self.paused {
+            self.apply_glitch(img);
+        }
+
+        // Output orientation for mirrored / upside-down panels
+        if self.flip_horizontal {
+            image::imageops::flip_horizontal_in_place(img);
+        }
+        if self.flip_vertical {
+            image::imageops::flip_vertical_in_place(img);
+        }
+    }
+
+    /// Render, recording [`stats()`](Self::stats) when enabled
+    fn render_counted(&mut self, canvas: &mut impl Canvas) {
+        #[cfg(feature = "stats")]
+        {
+            let mut counting = draw::Counting::new(canvas);
+            self.render(&mut counting);
+            self.draw_stats = counting.finish();
+        }
+        #[cfg(not(feature = "stats"))]
+        self.render(canvas);
+    }
+
+    /// Drawing work done for the last frame drawn to pixels
+    ///
+    /// Covers [`draw_into()`](Self::draw_into) and everything built on it;
+    /// SVG output isn't counted. Requires the `stats` feature.
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> DrawStats {
+        self.draw_stats
+    }
+
+    /// Advance animation state to `current_time` unless paused
+    fn advance(&mut self, current_time: u64) {
+        self.last_external_time = current_time;
+        if self.paused {
+            // Frozen: keep the last computed state
+            return;
+        }
+
+        // Shift the clock back by however long we were paused
+        if let Some(paused_at) = self.paused_at.take() {
+            self.time_offset = self
+                .time_offset
+                .wrapping_add(elapsed_since(current_time, paused_at));
+        }
+        self.current_time = current_time.wrapping_sub(self.time_offset);
+
+        self.update();
+    }
+
+    /// Advance all animation state to `self.current_time`
+    fn update(&mut self) {
+        let first_frame = self.last_update.is_none();
+
+        // The clock went backwards (a reset, or a 32-bit timer wrapping
+        // to 0): move every timer back by the same amount so nothing waits
+        // for the old timestamps to come around again
+        if let Some(last) = self
+            .last_update
+            .filter(|&t| !time_reached(self.current_time, t))
+        {
+            self.shift_timers(last.wrapping_sub(self.current_time));
+        }
+
+        // Scale a custom tween factor by the time since the last frame
+        let dt = self
+            .last_update
+            .map_or(TWEEN_FRAME_MS, |t| elapsed_since(self.current_time, t));
+        self.last_update = Some(self.current_time);
+        self.frame_ms = dt;
+        let per_frame = |f: f32| 1.0 - (1.0 - f).powf(dt as f32 / TWEEN_FRAME_MS as f32);
+        self.tween_step = self.tween_factor.map(per_frame);
+        self.gaze_step = self.gaze_factor.map(per_frame).or(self.tween_step);
+
+        // 1. Pre-calculation: Tween values
+        self.process_pulses();
+        self.update_curious_mode();
+        self.update_eye_heights();
+
+        // Tween heights, by elapsed time if a blink speed is set
+        let l_target = self.eye_l_height_next + self.eye_l_height_offset;
+        let r_target = self.eye_r_height_next + self.eye_r_height_offset;
+        if let Some((close_ms, open_ms)) = self.blink_speed {
+            let now = self.current_time;
+            let slowed = |ms: u64, speed: f32| (ms as f32 / speed) as u64;
+            self.eye_l_height_current = self.eye_l_height_timed.step(
+                self.eye_l_height_current,
+                l_target,
+                now,
+                self.eye_l_height_default,
+                slowed(close_ms, self.eye_l_speed),
+                slowed(open_ms, self.eye_l_speed),
+            );
+            self.eye_r_height_current = self.eye_r_height_timed.step(
+                self.eye_r_height_current,
+                r_target,
+                now,
+                self.eye_r_height_default,
+                slowed(close_ms, self.eye_r_speed),
+                slowed(open_ms, self.eye_r_speed),
+            );
+        } else if let Some(easing) = self.easing {
+            let (now, dt) = (self.current_time, self.frame_ms);
+            self.eye_l_height_current = self
+                .eye_l_height_eased
+                .step(
+                    self.eye_l_height_current as i32,
+                    l_target as i32,
+                    now,
+                    dt,
+                    easing,
+                )
+                .max(1) as u32;
+            self.eye_r_height_current = self
+                .eye_r_height_eased
+                .step(
+                    self.eye_r_height_current as i32,
+                    r_target as i32,
+                    now,
+                    dt,
+                    easing,
+                )
+                .max(1) as u32;
+        } else {
+            self.eye_l_height_current =
+                self.tween_height(self.eye_l_height_current, l_target, self.eye_l_speed);
+            self.eye_r_height_current =
+                self.tween_height(self.eye_r_height_current, r_target, self.eye_r_speed);
+        }
+
+        if self.eye_l_open
+            && self.eye_l_height_current <= self.closed_height + self.eye_l_height_offset
+        {
+            self.eye_l_height_next = self.open_height(self.eye_l_height_default);
+        }
+        if self.eye_r_open
+            && self.eye_r_height_current <= self.closed_height + self.eye_r_height_offset
+        {
+            self.eye_r_height_next = self.open_height(self.eye_r_height_default);
+        }
+
+        self.space_between = self.tween_u32(self.space_between, self.space_between_next);
+
+        // Collapse or grow the right eye; settings made before the first
+        // frame apply immediately
+        let target = if self.cyclops { 1.0 } else { 0.0 };
+        let k = if first_frame {
+            1.0
+        } else {
+            self.tween_step.unwrap_or(0.5)
+        };
+        self.cyclops_progress += (target - self.cyclops_progress) * k;
+        if (target - self.cyclops_progress).abs() < 0.05 {
+            self.cyclops_progress = target;
+        }
+
+        self.tween_positions();
+
+        self.border_radius_next = (
+            self.mood_radius(self.border_radius_default.0, &self.eye_l),
+            self.mood_radius(self.border_radius_default.1, &self.eye_r),
+        );
+        self.eye_l.border_radius =
+            self.tween_u32(self.eye_l.border_radius, self.border_radius_next.0);
+        self.eye_r.border_radius =
+            self.tween_u32(self.eye_r.border_radius, self.border_radius_next.1);
+
+        // 2. Animation processing
+        self.process_queue();
+        self.process_blink_pattern();
+        self.process_path();
+        self.process_autoblinker();
+        self.process_laugh();
+        self.process_confused();
+        self.process_shiver();
+        self.process_acknowledge();
+        self.process_startle();
+        self.process_wake();
+        self.process_peek();
+        self.process_wince();
+        self.process_idle();
+        self.process_saccades();
+        self.apply_flicker();
+
+        self.update_mood_transitions();
+
+        if self.sweat {
+            self.update_sweat();
+        }
+
+        self.process_blink_events();
+    }
+
+    /// Render the current animation state into `img`
+    fn render(&self, canvas: &mut impl Canvas) {
+        // Clear buffer
+        canvas.fill(self.bg());
+
+        // Scanner replaces the eyes entirely
+        if self.scanner {
+            self.draw_scanner(canvas);
+            return;
+        }
+
+        // 3. Shape drawing (rects include the eye scale for Confuse mood)
+        let eyes = self.drawn_eyes();
+        if self.shadow {
+            self.draw_shadow(canvas, &eyes);
+        }
+        for eye in &eyes {
+            let Rect { x, y, w, h } = eye.rect;
+            canvas.rounded_rect_corners(x, y, w, h, self.corner_radii(eye.geometry), self.fg());
+        }
+
+        if self.pupils || self.iris.is_some() {
+            self.draw_pupils(canvas, &eyes);
+        }
+
+        // 4. Mood overlays
+        self.draw_eyelids(canvas, &eyes);
+        if self.wince && !self.wince_toggle {
+            self.draw_wince_wrinkles(canvas, &eyes);
+        }
+
+        // 5. Loading animation (blue flickering)
+        if self.mood == Mood::Loading {
+            self.draw_loading(canvas, &eyes);
+        }
+
+        // 6. Sweat animation
+        if self.sweat {
+            self.draw_sweat(canvas);
+        }
+
+        // 7. Eyebrows
+        if self.eyebrows {
+            self.draw_eyebrows(canvas, &eyes);
+        }
+
+        // 8. Thinking spinner
+        if self.thinking {
+            let (x, y) = self.thinking_dot();
+            let size = THINKING_DOT_SIZE as i32;
+            canvas.rounded_rect(
+                x - size / 2,
+                y - size / 2,
+                THINKING_DOT_SIZE,
+                THINKING_DOT_SIZE,
+                THINKING_DOT_SIZE / 2,
+                self.fg(),
+            );
+        }
+
+        // 9. Status line
+        if !self.status_text.is_empty() {
+            self.draw_status_text(canvas);
+        }
+    }
+
+    fn draw_status_text(&self, canvas: &mut impl Canvas) {
+        let width = draw::text_width(&self.status_text) as i32;
+        let screen = self.screen_width as i32;
+        let x = if width <= screen {
+            half_rounded(screen - width)
+        } else {
+            // Enter from the right edge, leave on the left, repeat
+            let travel = (width + screen) as u64;
+            screen - ((self.current_time / STATUS_SCROLL_MS) % travel) as i32
+        };
+        let y = self.screen_height as i32 - (draw::GLYPH_SIZE.1 + STATUS_MARGIN) as i32;
+        canvas.text(x, y, &self.status_text, self.fg());
+    }
+
+    /// Draw a frame of the robot eyes animation
+    ///
+    /// Creates a new image buffer each call. For animation loops, use [`draw_into()`]
+    /// instead to avoid allocating a new buffer every frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_time` - Current timestamp in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// A new grayscale image buffer.
+    pub fn draw_eyes(&mut self, current_time: u64) -> GrayImage {
+        let (width, height) = self.output_size();
+        let mut img = GrayImage::new(width, height);
+        self.draw_into(&mut img, current_time);
+        img
+    }
+
+    /// Draw a frame as an SVG document
+    ///
+    /// Advances the animation like [`draw_eyes()`](Self::draw_eyes) but emits
+    /// the shapes (`<rect>` eyes with rounded corners, `<polygon>` eyelids,
+    /// sweat drops, ...) instead of pixels, for resolution-independent output.
+    /// The view box is the screen size; rotation, flips and the glitch
+    /// effect are not applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_time` - Current timestamp in milliseconds
+    pub fn render_svg(&mut self, current_time: u64) -> String {
+        self.advance(current_time);
+        let mut svg = Svg::new(self.screen_width, self.screen_height);
+        self.render(&mut svg);
+        svg.finish()
+    }
+
+    /// Render only the mood overlay of a frame as a mask
+    ///
+    /// Advances the animation like [`draw_eyes()`](Self::draw_eyes) but
+    /// draws just the eyelid shapes (tired, angry, happy, skeptical) as 255
+    /// on a 0 background, without the eye bodies, so they can be
+    /// post-processed or composited separately. The mask has the screen
+    /// size; rotation and flips are not applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_time` - Current timestamp in milliseconds
+    pub fn render_mood_mask(&mut self, current_time: u64) -> GrayImage {
+        self.advance(current_time);
+        let mut img = GrayImage::new(self.screen_width, self.screen_height);
+
+        // Eyelids are drawn in the background color; make that the mask color
+        let colors = (self.bg_color, self.inverted);
+        (self.bg_color, self.inverted) = (255, false);
+        self.draw_eyelids(&mut Raster::new(&mut img), &self.drawn_eyes());
+        (self.bg_color, self.inverted) = colors;
+        img
+    }
+
+    /// Draw a frame into an RGB image through the palette
+    ///
+    /// See [`set_palette()`](Self::set_palette) for how gray levels map to
+    /// colors. `img` must have the size of [`output_size()`](Self::output_size).
+    ///
+    /// # Arguments
+    ///
+    /// * `img` - RGB image buffer to draw into
+    /// * `current_time` - Current timestamp in milliseconds
+    pub fn draw_into_rgb(&mut self, img: &mut RgbImage, current_time: u64) {
+        let gray = self.draw_eyes(current_time);
+        let default_palette = [Rgb([self.bg(); 3]), Rgb([self.fg(); 3])];
+        let palette = self.palette.as_deref().unwrap_or(&default_palette);
+
+        let (bg, fg) = (self.bg() as f32, self.fg() as f32);
+        let last = (palette.len() - 1) as f32;
+        for (out, pixel) in img.pixels_mut().zip(gray.pixels()) {
+            let coverage = if fg == bg {
+                0.0
+            } else {
+                ((pixel[0] as f32 - bg) / (fg - bg)).clamp(0.0, 1.0)
+            };
+            *out = palette[(coverage * last).round() as usize];
+        }
+
+        if let Some((top, bottom)) = self.eye_gradient {
+            let back = palette[0];
+            let map = self.gradient_map();
+            for ((out, pixel), row) in img.pixels_mut().zip(gray.pixels()).zip(map.pixels()) {
+                if row[0] == 0 || fg == bg {
+                    continue;
+                }
+                let coverage = ((pixel[0] as f32 - bg) / (fg - bg)).clamp(0.0, 1.0);
+                let color = lerp_rgb(top, bottom, (row[0] - 1) as f32 / 254.0);
+                *out = lerp_rgb(back, color, coverage);
+            }
+        }
+    }
+
+    /// Position within the eye for each output pixel, for the RGB gradient
+    ///
+    /// 0 outside the eyes, 1 on an eye's top row up to 255 on its bottom
+    /// row. Oriented like the output frame.
+    fn gradient_map(&self) -> GrayImage {
+        let mut map = GrayImage::new(self.screen_width, self.screen_height);
+        for DrawnEye { rect, .. } in self.drawn_eyes() {
+            let span = rect.h.saturating_sub(1).max(1) as f32;
+            for dy in 0..rect.h {
+                let y = rect.y + dy as i32;
+                let level = 1 + (dy as f32 / span * 254.0).round() as u8;
+                for x in rect.x..rect.x + rect.w as i32 {
+                    if x >= 0 && y >= 0 && (x as u32) < map.width() && (y as u32) < map.height() {
+                        map.put_pixel(x as u32, y as u32, Luma([level]));
+                    }
+                }
+            }
+        }
+
+        if self.flip_horizontal {
+            image::imageops::flip_horizontal_in_place(&mut map);
+        }
+        if self.flip_vertical {
+            image::imageops::flip_vertical_in_place(&mut map);
+        }
+        match self.rotation {
+            Rotation::Deg0 => map,
+            Rotation::Deg90 => image::imageops::rotate90(&map),
+            Rotation::Deg180 => image::imageops::rotate180(&map),
+            Rotation::Deg270 => image::imageops::rotate270(&map),
+        }
+    }
+
+    /// Draw a frame into a packed `0xAARRGGBB` buffer
+    ///
+    /// Convenient for `minifb`, `<canvas>` or GPU textures. Each gray level
+    /// (including the configured fg/bg colors) is expanded to an opaque
+    /// gray ARGB word.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Output buffer of exactly `width * height` words (see [`output_size()`](Self::output_size))
+    /// * `current_time` - Current timestamp in milliseconds
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BotEyesError::BufferSize`] without drawing if `buf` has the
+    /// wrong length; its `actual` size is the buffer length as a single row.
+    pub fn draw_into_argb(
+        &mut self,
+        buf: &mut [u32],
+        current_time: u64,
+    ) -> Result<(), BotEyesError> {
+        let (width, height) = self.output_size();
+        if buf.len() != width as usize * height as usize {
+            return Err(buffer_len_error((width, height), buf.len()));
+        }
+
+        let img = self.draw_eyes(current_time);
+        for (out, pixel) in buf.iter_mut().zip(img.pixels()) {
+            *out = gray_to_argb(pixel[0]);
+        }
+
+        Ok(())
+    }
+
+    /// Draw a frame into a packed 1-bit-per-pixel buffer
+    ///
+    /// Rows are stored top to bottom, most significant bit first, each row
+    /// padded to a whole byte (the layout of embedded-graphics `ImageRaw`
+    /// with `BinaryColor`). A bit is set when the gray level reaches the
+    /// [binary threshold](Self::set_binary_threshold).
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Output buffer of exactly `width.div_ceil(8) * height` bytes (see [`output_size()`](Self::output_size))
+    /// * `current_time` - Current timestamp in milliseconds
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BotEyesError::BufferSize`] without drawing if `buf` has the
+    /// wrong length; its `actual` size is the buffer length in bytes as a
+    /// single row.
+    pub fn draw_packed(&mut self, buf: &mut [u8], current_time: u64) -> Result<(), BotEyesError> {
+        let (width, height) = self.output_size();
+        let stride = width.div_ceil(8) as usize;
+        if buf.len() != stride * height as usize {
+            return Err(buffer_len_error((width, height), buf.len()));
+        }
+
+        self.draw_into_sink(&mut draw::Packed { buf, stride }, current_time);
+        Ok(())
+    }
+
+    /// Draw a frame into a one-bit [`PixelSink`]
+    ///
+    /// A pixel is lit when its gray level reaches the
+    /// [binary threshold](Self::set_binary_threshold); the drop shadow is
+    /// skipped since its half-tone would threshold into solid pixels.
+    /// Frames are [`output_size()`](Self::output_size); anything outside
+    /// the sink is clipped by it.
+    ///
+    /// The shapes are rasterized straight into the sink as runs of
+    /// [`fill_span()`](PixelSink::fill_span), without a gray framebuffer.
+    /// Effects applied to the finished frame (rotation, flips, glitch,
+    /// anti-aliasing, split colors) need one, so with any of them enabled a
+    /// gray frame is rendered, thresholded and written to the cleared sink.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - Output target, e.g. a `GrayImage`, `RgbImage` or [`RawBuffer`]
+    /// * `current_time` - Current timestamp in milliseconds
+    pub fn draw_into_sink<S: PixelSink + ?Sized>(&mut self, sink: &mut S, current_time: u64) {
+        let shadow = std::mem::replace(&mut self.shadow, false);
+        if self.has_output_effects() {
+            let img = self.draw_eyes(current_time);
+            self.write_thresholded(&img, sink);
+        } else {
+            // The background fill covers every pixel, so no clear is needed
+            self.advance(current_time);
+            let (width, height) = (self.screen_width, self.screen_height);
+            let mut target = draw::Threshold {
+                sink,
+                threshold: self.binary_threshold,
+            };
+            self.render_counted(&mut Raster::with_size(&mut target, width, height));
+        }
+        self.shadow = shadow;
+    }
+
+    /// Whether drawing needs the whole frame after rendering the shapes
+    fn has_output_effects(&self) -> bool {
+        self.rotation != Rotation::Deg0
+            || self.flip_horizontal
+            || self.flip_vertical
+            || self.glitch
+            || self.antialias
+            || self.split_color.is_some()
+    }
+
+    /// Clear `sink` and light the pixels of `img` at the binary threshold
+    fn write_thresholded<S: PixelSink + ?Sized>(&self, img: &GrayImage, sink: &mut S) {
+        sink.clear();
+        for (y, row) in img.rows().enumerate() {
+            let y = y as u32;
+            let mut start = None;
+            for (x, pixel) in row.enumerate() {
+                match (pixel[0] >= self.binary_threshold, start) {
+                    (true, None) => start = Some(x as u32),
+                    (false, Some(x0)) => {
+                        sink.fill_span(y, x0, x as u32, true);
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(x0) = start {
+                sink.fill_span(y, x0, img.width(), true);
+            }
+        }
+    }
+
+    // =====================================================================
+    // Export
+    // =====================================================================
+
+    /// Set how many pixels each character covers in [`render_ascii()`](Self::render_ascii)
+    ///
+    /// Defaults to 2x4, which roughly compensates for tall terminal glyphs.
+    /// Zero values are treated as 1.
+    pub fn set_ascii_cell_size(&mut self, width: u32, height: u32) -> &mut Self {
+        self.ascii_cell_width = width.max(1);
+        self.ascii_cell_height = height.max(1);
+        self
+    }
+
+    /// Render a frame as ASCII art for terminal previews
+    ///
+    /// Each character represents one cell (see [`set_ascii_cell_size()`](Self::set_ascii_cell_size)),
+    /// mapped by average brightness: `' '` background, `'.'`/`'+'` partial, `'#'` lit.
+    /// Every row ends with a newline.
+    pub fn render_ascii(&mut self, current_time: u64) -> String {
+        const RAMP: [char; 4] = [' ', '.', '+', '#'];
+
+        let img = self.draw_eyes(current_time);
+        let (width, height) = img.dimensions();
+        let cell_w = self.ascii_cell_width;
+        let cell_h = self.ascii_cell_height;
+        let cols = width.div_ceil(cell_w);
+        let rows = height.div_ceil(cell_h);
+
+        let mut out = String::with_capacity(((cols + 1) * rows) as usize);
+        for row in 0..rows {
+            for col in 0..cols {
+                let x0 = col * cell_w;
+                let y0 = row * cell_h;
+                let x1 = (x0 + cell_w).min(width);
+                let y1 = (y0 + cell_h).min(height);
+
+                let mut sum = 0u32;
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        sum += img.get_pixel(x, y)[0] as u32;
+                    }
+                }
+                let avg = sum / ((x1 - x0) * (y1 - y0));
+                // Distance from background relative to the fg/bg contrast
+                let diff = avg.abs_diff(self.bg() as u32);
+                let range = (self.fg() as u32).abs_diff(self.bg() as u32).max(1);
+                let idx = if diff == 0 {
+                    0
+                } else {
+                    (1 + diff * 3 / (range + 1)) as usize
+                };
+                out.push(RAMP[idx.min(RAMP.len() - 1)]);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Lazily render successive animation frames
+    ///
+    /// Each item steps the animation `frame_ms` milliseconds past the
+    /// previous one (starting after the last drawn timestamp), like
+    /// [`render_sprite_sheet()`](Self::render_sprite_sheet) but one frame at
+    /// a time. Frames are only drawn as the iterator is advanced.
+    pub fn frames(&mut self, count: u32, frame_ms: u64) -> impl Iterator<Item = GrayImage> + '_ {
+        let start = self.last_external_time;
+        (1..=count as u64).map(move |i| self.draw_eyes(start.wrapping_add(i * frame_ms)))
+    }
+
+    /// Render an animation as a horizontal sprite sheet
+    ///
+    /// Steps the animation `frames` times, `frame_ms` milliseconds apart
+    /// (starting after the last drawn timestamp), and places the frames side
+    /// by side in a single `width * frames` x `height` image
+    /// (see [`output_size()`](Self::output_size)).
+    ///
+    /// # Panics
+    ///
+    /// If the sheet would be wider than `u32::MAX` pixels. Nothing is drawn
+    /// in that case; use [`frames()`](Self::frames) for long animations.
+    pub fn render_sprite_sheet(&mut self, frames: u32, frame_ms: u64) -> GrayImage {
+        use image::GenericImage;
+
+        let (width, height) = self.output_size();
+        let sheet_width = width
+            .checked_mul(frames)
+            .expect("sprite sheet wider than u32::MAX pixels");
+        let mut sheet = GrayImage::new(sheet_width, height);
+        let mut buffer = GrayImage::new(width, height);
+        let start = self.last_external_time;
+        for i in 0..frames {
+            self.draw_into(&mut buffer, start.wrapping_add((i as u64 + 1) * frame_ms));
+            sheet
+                .copy_from(&buffer, i * width, 0)
+                .expect("frame fits inside sprite sheet");
+        }
+        sheet
+    }
+
+    /// Render an animation to a looping GIF file
+    ///
+    /// Steps the animation `frames` times, `frame_ms` milliseconds apart,
+    /// starting after the last drawn timestamp. The GIF uses a 256-level
+    /// grayscale palette so rendered colors are kept as-is.
+    ///
+    /// Requires the `gif` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - Number of frames to render
+    /// * `frame_ms` - Time between frames in milliseconds (GIF delay has 10ms resolution)
+    /// * `path` - Output file path
+    #[cfg(feature = "gif")]
+    pub fn render_gif<P: AsRef<std::path::Path>>(
+        &mut self,
+        frames: u32,
+        frame_ms: u64,
+        path: P,
+    ) -> std::io::Result<()> {
+        use std::io;
+
+        let (out_width, out_height) = self.output_size();
+        let width = u16::try_from(out_width)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "screen too wide for GIF"))?;
+        let height = u16::try_from(out_height)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "screen too tall for GIF"))?;
+
+        // Palette index == gray level
+        let palette: Vec<u8> = (0..=255u8).flat_map(|v| [v, v, v]).collect();
+        let file = io::BufWriter::new(std::fs::File::create(path)?);
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        let delay = (frame_ms / 10).min(u16::MAX as u64) as u16;
+        let start = self.last_external_time;
+        let mut buffer = GrayImage::new(out_width, out_height);
+        for i in 1..=frames as u64 {
+            self.draw_into(&mut buffer, start.wrapping_add(i * frame_ms));
+            let mut frame =
+                gif::Frame::from_indexed_pixels(width, height, buffer.as_raw().clone(), None);
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    // =====================================================================
+    // Private Helper Methods
+    // =====================================================================
+
+    fn update_curious_mode(&mut self) {
+        let (left_target, right_target) = if self.curious {
+            let threshold = self.curious_threshold;
+            let left_offset = self.eye_l_x_next <= threshold
+                || (self.eye_l_x_next >= self.get_constraint_x() - threshold && self.cyclops);
+            let right_offset =
+                self.eye_r_x_next >= self.screen_width as i32 - self.eye_r.width as i32 - threshold;
+            (
+                if left_offset { self.curious_amount } else { 0 },
+                if right_offset { self.curious_amount } else { 0 },
+            )
+        } else {
+            (0, 0)
+        };
+
+        let grow = if self.startle { STARTLE_GROW } else { 0 };
+        let (left_target, right_target) = (left_target + grow, right_target + grow);
+
+        // Grow and shrink smoothly instead of popping
+        self.eye_l_height_offset = self.tween_u32(self.eye_l_height_offset, left_target);
+        self.eye_r_height_offset = self.tween_u32(self.eye_r_height_offset, right_target);
+    }
+
+    fn update_eye_heights(&mut self) {
+        // Update Y position for vertical centering based on height change
+        // When height changes, adjust Y to keep the eye centered vertically
+        self.eye_l_y -= self.eye_l_height_offset as i32 / 2;
+        self.eye_r_y -= self.eye_r_height_offset as i32 / 2;
+    }
+
+    fn tween_positions(&mut self) {
+        let (dx, dy) = self.cluster_offset_clamped();
+        if let Some(easing) = self.easing {
+            let (now, dt) = (self.current_time, self.frame_ms);
+            self.eye_l_x =
+                self.eye_l_x_eased
+                    .step(self.eye_l_x, self.eye_l_x_next + dx, now, dt, easing);
+            // The curve ignores the current value between restarts, so the
+            // curious-mode lift goes into the target instead, and is taken
+            // back out of the value a restart starts from
+            let lift = self.eye_l_height_offset as i32 / 2;
+            self.eye_l_y = self.eye_l_y_eased.step(
+                self.eye_l_y + lift,
+                self.eye_l_y_next + dy - lift,
+                now,
+                dt,
+                easing,
+            );
+
+            self.eye_r_x_next = self.right_eye_x(self.eye_l_x_next);
+            self.eye_r_y_next = self.eye_l_y_next;
+            self.eye_r_x = self.right_eye_x(self.eye_l_x);
+            self.eye_r_y = self.eye_l_y;
+            return;
+        }
+
+        self.eye_l_x = self.tween_gaze(self.eye_l_x, self.eye_l_x_next + dx);
+        self.eye_l_y = self.tween_gaze(self.eye_l_y, self.eye_l_y_next + dy);
+
+        self.eye_r_x_next = self.right_eye_x(self.eye_l_x_next);
+        self.eye_r_y_next = self.eye_l_y_next;
+
+        self.eye_r_x = self.tween_gaze(self.eye_r_x, self.eye_r_x_next + dx);
+        self.eye_r_y = self.tween_gaze(self.eye_r_y, self.eye_r_y_next + dy);
+    }
+
+    /// Cluster offset, limited so at least a sliver of the eyes stays on screen
+    fn cluster_offset_clamped(&self) -> (i32, i32) {
+        let max_x = (self.screen_width as i32 - self.get_constraint_x() - 1).max(0);
+        let max_y = (self.eye_l.height as i32 - 1).max(0);
+        let (dx, dy) = self.cluster_offset;
+        (dx.clamp(-max_x, max_x), dy.clamp(-max_y, max_y))
+    }
+
+    /// X position of the right eye for a left eye at `left_x`
+    fn right_eye_x(&self, left_x: i32) -> i32 {
+        let shown = 1.0 - self.cyclops_progress;
+        // A surviving right eye slides onto the left eye's spot
+        let gap = match self.cyclops_eye {
+            Side::Left => self.eye_l.width as f32 + self.space_between as f32 * shown,
+            Side::Right => (self.eye_l.width + self.space_between) as f32 * shown,
+        };
+        left_x + gap.round() as i32 + self.middle_eye_count() as i32 * self.eye_stride()
+    }
+
+    /// Tween a position with the gaze speed if one is set
+    fn tween_gaze(&self, current: i32, next: i32) -> i32 {
+        match self.gaze_step {
+            Some(factor) => tween_i32_by(current, next, factor),
+            None => tween_i32(current, next),
+        }
+    }
+
+    /// Tween with the custom factor if one is set, else halfway
+    fn tween_i32(&self, current: i32, next: i32) -> i32 {
+        match self.tween_step {
+            Some(factor) => tween_i32_by(current, next, factor),
+            None => tween_i32(current, next),
+        }
+    }
+
+    /// Tween an eye height, scaling the step by that eye's speed
+    fn tween_height(&self, current: u32, next: u32, speed: f32) -> u32 {
+        if speed == 1.0 {
+            return self.tween_u32(current, next);
+        }
+        let factor = (self.tween_step.unwrap_or(0.5) * speed).clamp(0.01, 1.0);
+        tween_i32_by(current as i32, next as i32, factor) as u32
+    }
+
+    /// Unsigned variant of [`tween_i32()`](Self::tween_i32)
+    fn tween_u32(&self, current: u32, next: u32) -> u32 {
+        self.tween_i32(current as i32, next as i32) as u32
+    }
+
+    fn get_constraint_x(&self) -> i32 {
+        // Cyclops: the other eye and spacing collapse to nothing
+        if self.cyclops {
+            let width = match self.cyclops_eye {
+                Side::Left => self.eye_l.width,
+                Side::Right => self.eye_r.width,
+            };
+            return self.screen_width as i32 - width as i32;
+        }
+        (self.screen_width as i32)
+            - self.eye_l.width as i32
+            - self.space_between as i32
+            - self.eye_r.width as i32
+            - self.middle_eye_count() as i32 * self.eye_stride()
+    }
+
+    /// Total width of the row of eyes for the given eye width and spacing
+    fn row_width(&self, width: u32, space: u32) -> u64 {
+        let count = self.eye_count as u64;
+        count * width as u64 + (count - 1) * space as u64
+    }
+
+    /// Number of eyes between the left and right eye
+    fn middle_eye_count(&self) -> u8 {
+        if self.cyclops {
+            0
+        } else {
+            self.eye_count.saturating_sub(2)
+        }
+    }
+
+    /// Horizontal distance from one eye's left edge to the next
+    fn eye_stride(&self) -> i32 {
+        self.eye_l.width as i32 + self.space_between as i32
+    }
+
+    fn get_constraint_y(&self) -> i32 {
+        (self.screen_height - self.status_row_height()) as i32 - self.eye_l.height as i32
+    }
+
+    /// Rows kept clear of the eyes for the status line
+    fn status_row_height(&self) -> u32 {
+        if self.status_text.is_empty() {
+            0
+        } else {
+            (draw::GLYPH_SIZE.1 + 2 * STATUS_MARGIN).min(self.screen_height)
+        }
+    }
+    fn process_autoblinker(&mut self) {
+        if !self.autoblinker || self.blink_pattern_until.is_some() || self.wake || self.wince {
+            return;
+        }
+        if self
+            .double_blink_at
+            .is_some_and(|at| time_reached(self.current_time, at))
+        {
+            self.blink();
+            self.double_blink_at = None;
+        }
+        if time_reached(self.current_time, self.blink_timer) {
+            self.blink();
+            let delay =
+                self.blink_config.interval * 1000 + self.random_delay(self.blink_config.variation);
+            self.blink_timer = self.current_time.wrapping_add(delay);
+
+            // Only draw from the RNG when enabled so seeded runs don't change
+            let chance = self.blink_config.double_blink_chance;
+            if chance > 0.0 && self.rng.gen_bool(chance as f64) {
+                self.double_blink_at = Some(self.current_time.wrapping_add(DOUBLE_BLINK_GAP_MS));
+            }
+        }
+    }
+
+    /// Breathing and heartbeat height pulses
+    fn process_pulses(&mut self) {
+        let breathing = self.breathing && self.breathing_period > 0;
+        let heartbeat = self.heartbeat && self.heartbeat_bpm > 0;
+        if !breathing && !heartbeat {
+            return;
+        }
+
+        // Leave heights alone while a blink is closing the eyes
+        let blinking = self.eye_l_height_next == self.closed_height
+            || self.eye_r_height_next == self.closed_height;
+        if blinking || self.wake || self.wince {
+            return;
+        }
+        if self.confused || self.laugh {
+            self.restore_open_heights();
+            return;
+        }
+
+        let mut delta = 0.0;
+        if breathing {
+            let phase = (self.current_time % self.breathing_period) as f32
+                / self.breathing_period as f32
+                * std::f32::consts::TAU;
+            delta += phase.sin() * self.breathing_amplitude as f32;
+        }
+        if heartbeat {
+            let period = 60_000 / self.heartbeat_bpm as u64;
+            let phase = (self.current_time % period.max(1)) as f32 / period.max(1) as f32;
+            delta += heartbeat_wave(phase) * HEARTBEAT_AMPLITUDE;
+        }
+        let delta = delta.round() as i32;
+        let l_open = self.open_height(self.eye_l_height_default) as i32;
+        let r_open = self.open_height(self.eye_r_height_default) as i32;
+        self.eye_l_height_next = (l_open + delta).max(1) as u32;
+        self.eye_r_height_next = (r_open + delta).max(1) as u32;
+    }
+
+    /// Reset open (not closing) eyes to their default height target
+    fn restore_open_heights(&mut self) {
+        if self.eye_l_height_next != self.closed_height {
+            self.eye_l_height_next = self.open_height(self.eye_l_height_default);
+        }
+        if self.eye_r_height_next != self.closed_height {
+            self.eye_r_height_next = self.open_height(self.eye_r_height_default);
+        }
+    }
+
+    /// Height open eyes settle at, after the squint ratio
+    fn open_height(&self, default: u32) -> u32 {
+        ((default as f32 * self.eye_height_ratio).round() as u32).max(1)
+    }
+
+    fn process_queue(&mut self) {
+        let busy = self.confused
+            || self.laugh
+            || self.shiver
+            || self.acknowledge
+            || self.startle
+            || self.wake
+            || self.peek
+            || self.wince
+            || self.blink_pattern_until.is_some()
+            || self.blink_in_progress
+            || !time_reached(self.current_time, self.anim_wait_until);
+        if busy {
+            return;
+        }
+
+        match self.anim_queue.pop_front() {
+            Some(Anim::Blink) => self.blink(),
+            Some(Anim::Wink(left)) => self.blink_eyes(left, !left),
+            Some(Anim::Confused) => self.anim_confused(),
+            Some(Anim::Laugh) => self.anim_laugh(),
+            Some(Anim::Shiver(ms)) => self.anim_shiver(ms),
+            Some(Anim::Wait(ms)) => self.anim_wait_until = self.current_time.wrapping_add(ms),
+            None => {}
+        }
+    }
+
+    fn process_blink_pattern(&mut self) {
+        if self
+            .blink_pattern_until
+            .is_some_and(|until| !time_reached(self.current_time, until))
+        {
+            return;
+        }
+
+        match self.blink_pattern.pop_front() {
+            Some((closed, ms)) => {
+                if closed {
+                    self.close();
+                } else {
+                    self.open();
+                }
+                self.blink_pattern_until = Some(self.current_time.wrapping_add(ms));
+            }
+            None => {
+                if self.blink_pattern_until.take().is_some() {
+                    self.open();
+                }
+            }
+        }
+    }
+
+    fn process_path(&mut self) {
+        if self.path.is_empty()
+            || self
+                .path_until
+                .is_some_and(|until| !time_reached(self.current_time, until))
+        {
+            return;
+        }
+
+        if self.path_step >= self.path.len() {
+            if !self.path_loop {
+                self.path_until = None;
+                return;
+            }
+            self.path_step = 0;
+        }
+        let (x, y, dwell) = self.path[self.path_step];
+        let (min_x, max_x) = self.gaze_range_x();
+        let (min_y, max_y) = self.gaze_range_y();
+        self.eye_l_x_next = x.clamp(min_x, max_x);
+        self.eye_l_y_next = y.clamp(min_y, max_y);
+        self.path_step += 1;
+        self.path_until = Some(self.current_time.wrapping_add(dwell));
+    }
+
+    /// Move every start time and deadline `back` milliseconds earlier
+    ///
+    /// Keeps elapsed and remaining times intact across a clock jump.
+    fn shift_timers(&mut self, back: u64) {
+        for timer in [
+            &mut self.blink_timer,
+            &mut self.idle_timer,
+            &mut self.saccade_timer,
+            &mut self.anim_wait_until,
+            &mut self.laugh_timer,
+            &mut self.confused_timer,
+            &mut self.shiver_timer,
+            &mut self.acknowledge_timer,
+            &mut self.startle_timer,
+            &mut self.wake_timer,
+            &mut self.peek_timer,
+            &mut self.wince_timer,
+            &mut self.eye_l_height_timed.since,
+            &mut self.eye_r_height_timed.since,
+            &mut self.eye_l_x_eased.since,
+            &mut self.eye_l_y_eased.since,
+            &mut self.eye_l_height_eased.since,
+            &mut self.eye_r_height_eased.since,
+        ] {
+            *timer = timer.wrapping_sub(back);
+        }
+        for deadline in [
+            &mut self.double_blink_at,
+            &mut self.idle_return_at,
+            &mut self.blink_pattern_until,
+            &mut self.path_until,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *deadline = deadline.wrapping_sub(back);
+        }
+    }
+
+    /// Whether an animation started at `started` has run for `duration`
+    ///
+    /// Never later than [`ANIM_WATCHDOG_MS`], so a huge duration can't
+    /// leave the animation flag stuck.
+    fn anim_expired(&self, started: u64, duration: u64) -> bool {
+        elapsed_since(self.current_time, started) >= duration.min(ANIM_WATCHDOG_MS)
+    }
+
+    fn process_laugh(&mut self) {
+        if self.laugh {
+            if self.laugh_toggle {
+                self.laugh_running_style = self.laugh_style;
+                self.laugh_timer = self.current_time;
+                self.laugh_toggle = false;
+            } else if self.anim_expired(
+                self.laugh_timer,
+                self.laugh_duration_once.unwrap_or(self.laugh_duration),
+            ) {
+                self.laugh_toggle = true;
+                self.laugh = false;
+                self.laugh_duration_once = None;
+            }
+        }
+    }
+
+    fn process_confused(&mut self) {
+        if self.confused {
+            if self.confused_toggle {
+                self.confused_timer = self.current_time;
+                self.confused_toggle = false;
+            } else if self.anim_expired(
+                self.confused_timer,
+                self.confused_duration_once
+                    .unwrap_or(self.confused_duration),
+            ) {
+                self.confused_toggle = true;
+                self.confused = false;
+                self.confused_duration_once = None;
+            }
+        }
+    }
+
+    fn process_shiver(&mut self) {
+        if self.shiver {
+            if self.shiver_toggle {
+                self.shiver_timer = self.current_time;
+                self.shiver_toggle = false;
+            } else if self.anim_expired(self.shiver_timer, self.shiver_duration) {
+                self.shiver_toggle = true;
+                self.shiver = false;
+            }
+        }
+    }
+
+    fn process_acknowledge(&mut self) {
+        if self.acknowledge {
+            if self.acknowledge_toggle {
+                // Re-triggered mid-nod: keep the gaze from before the first one
+                if self.acknowledge_prev.is_none() {
+                    self.acknowledge_prev = Some((self.eye_l_x_next, self.eye_l_y_next));
+                }
+                // Assigned directly so the gaze deadzone can't swallow the nod
+                (self.eye_l_x_next, self.eye_l_y_next) = self.position_target(Position::South);
+                self.blink();
+                self.acknowledge_timer = self.current_time;
+                self.acknowledge_toggle = false;
+            } else if self.anim_expired(self.acknowledge_timer, ACKNOWLEDGE_NOD_MS) {
+                if let Some((x, y)) = self.acknowledge_prev.take() {
+                    (self.eye_l_x_next, self.eye_l_y_next) = (x, y);
+                }
+                self.acknowledge_toggle = true;
+                self.acknowledge = false;
+            }
+        }
+    }
+
+    fn process_startle(&mut self) {
+        if self.startle {
+            if self.startle_toggle {
+                // Re-triggered mid-jump: already up, just hold longer
+                if self.startle_prev_y.is_none() {
+                    self.startle_prev_y = Some(self.eye_l_y_next);
+                    self.eye_l_y_next = (self.eye_l_y_next - STARTLE_JUMP).max(0);
+                }
+                self.startle_timer = self.current_time;
+                self.startle_toggle = false;
+            } else if self.anim_expired(self.startle_timer, STARTLE_MS) {
+                if let Some(y) = self.startle_prev_y.take() {
+                    self.eye_l_y_next = y;
+                }
+                self.startle_toggle = true;
+                self.startle = false;
+            }
+        }
+    }
+
+    fn process_wince(&mut self) {
+        if !self.wince {
+            return;
+        }
+        if self.wince_toggle {
+            self.wince_timer = self.current_time;
+            self.wince_toggle = false;
+        } else if self.anim_expired(self.wince_timer, self.wince_duration) {
+            // The open flags are left alone while squeezing, so eyes shut
+            // before or during the wince (see close()) stay shut
+            let settled = |open, default| {
+                if open {
+                    self.open_height(default)
+                } else {
+                    self.closed_height
+                }
+            };
+            let left = settled(self.eye_l_open, self.eye_l_height_default);
+            let right = settled(self.eye_r_open, self.eye_r_height_default);
+            (self.eye_l_height_next, self.eye_r_height_next) = (left, right);
+            self.wince_toggle = true;
+            self.wince = false;
+            return;
+        }
+
+        // Hold the eyes squeezed; re-applied every frame so blinks and
+        // mood changes can't reopen them early
+        let squeezed = |default| {
+            ((self.open_height(default) as f32 * WINCE_FRACTION).round() as u32)
+                .max(self.closed_height + 1)
+        };
+        let (left, right) = (
+            squeezed(self.eye_l_height_default),
+            squeezed(self.eye_r_height_default),
+        );
+        self.eye_l_height_next = left;
+        self.eye_r_height_next = right;
+    }
+
+    fn process_wake(&mut self) {
+        if !self.wake {
+            return;
+        }
+        if self.wake_toggle {
+            // Start from shut eyes, whatever they were doing
+            self.close();
+            self.eye_l_height_current = self.closed_height + self.eye_l_height_offset;
+            self.eye_r_height_current = self.closed_height + self.eye_r_height_offset;
+            self.wake_timer = self.current_time;
+            self.wake_toggle = false;
+        } else if self.anim_expired(self.wake_timer, WAKE_MS) {
+            self.open();
+            self.wake_toggle = true;
+            self.wake = false;
+            return;
+        }
+
+        let elapsed = elapsed_since(self.current_time, self.wake_timer);
+        let (_, fraction) = WAKE_STEPS
+            .iter()
+            .rev()
+            .find(|(at, _)| elapsed >= *at)
+            .copied()
+            .unwrap_or(WAKE_STEPS[0]);
+        let height = |default| {
+            ((self.open_height(default) as f32 * fraction).round() as u32).max(self.closed_height)
+        };
+        let (left, right) = (
+            height(self.eye_l_height_default),
+            height(self.eye_r_height_default),
+        );
+        self.eye_l_height_next = left;
+        self.eye_r_height_next = right;
+    }
+
+    fn process_peek(&mut self) {
+        if !self.peek {
+            return;
+        }
+        if self.peek_toggle {
+            // Jump just past the chosen edge, then let the tween bring the
+            // eyes to the centre
+            self.set_position(Position::Center);
+            let (dx, dy) = self.cluster_offset_clamped();
+            let row_width = self.screen_width as i32 - self.get_constraint_x();
+            let left = -row_width - dx;
+            let right = self.screen_width as i32 - dx;
+            let top = -(self.eye_l.height as i32) - dy;
+            let bottom = self.screen_height as i32 - dy;
+            let (x, y) = match self.peek_from {
+                Position::North => (None, Some(top)),
+                Position::NorthEast => (Some(right), Some(top)),
+                Position::East => (Some(right), None),
+                Position::SouthEast => (Some(right), Some(bottom)),
+                Position::South => (None, Some(bottom)),
+                Position::SouthWest => (Some(left), Some(bottom)),
+                Position::West => (Some(left), None),
+                Position::NorthWest => (Some(left), Some(top)),
+                Position::Center => (None, None),
+            };
+            self.eye_l_x = x.unwrap_or(self.eye_l_x_next) + dx;
+            self.eye_l_y = y.unwrap_or(self.eye_l_y_next) + dy;
+            self.eye_r_x = self.right_eye_x(self.eye_l_x);
+            self.eye_r_y = self.eye_l_y;
+            // Restart eased transitions from the off-screen spot
+            self.eye_l_x_eased = EasedTween::default();
+            self.eye_l_y_eased = EasedTween::default();
+            self.peek_timer = self.current_time;
+            self.peek_toggle = false;
+        } else {
+            let (dx, dy) = self.cluster_offset_clamped();
+            let arrived =
+                self.eye_l_x == self.eye_l_x_next + dx && self.eye_l_y == self.eye_l_y_next + dy;
+            if arrived || self.anim_expired(self.peek_timer, ANIM_WATCHDOG_MS) {
+                self.peek_toggle = true;
+                self.peek = false;
+            }
+        }
+    }
+
+    fn process_idle(&mut self) {
+        if !self.idle {
+            return;
+        }
+
+        // Resting phase: back to the centre until the next move
+        if self
+            .idle_return_at
+            .is_some_and(|at| time_reached(self.current_time, at))
+        {
+            self.eye_l_x_next = half_rounded(self.get_constraint_x().max(0));
+            self.eye_l_y_next = half_rounded(self.get_constraint_y().max(0));
+            self.idle_return_at = None;
+            if self.idle_instant {
+                self.snap_position();
+            }
+        }
+
+        if time_reached(self.current_time, self.idle_timer) {
+            // Calculate range based on percentage (centered).
+            // Eyes larger than the screen leave no room to move.
+            let (min_x, max_x) = self.gaze_range_x();
+            let (min_y, max_y) = self.gaze_range_y();
+            let x_range_pct = self.idle_config.x_range as f32 / 100.0;
+            let y_range_pct = self.idle_config.y_range as f32 / 100.0;
+
+            let x_range = ((max_x - min_x) as f32 * x_range_pct) as i32;
+            let y_range = ((max_y - min_y) as f32 * y_range_pct) as i32;
+
+            // Center the range within available space
+            let x_offset = min_x + half_rounded(max_x - min_x - x_range);
+            let y_offset = min_y + half_rounded(max_y - min_y - y_range);
+
+            self.eye_l_x_next = x_offset + self.idle_sample(x_range);
+            self.eye_l_y_next = y_offset + self.idle_sample(y_range);
+            let delay =
+                self.idle_config.interval * 1000 + self.random_delay(self.idle_config.variation);
+            self.idle_timer = self.current_time.wrapping_add(delay);
+            if self.idle_return {
+                self.idle_return_at = Some(self.current_time.wrapping_add(delay / 2));
+            }
+            if self.idle_instant {
+                self.snap_position();
+            }
+
+            self.on_idle_move.call();
+        }
+    }
+
+    fn process_saccades(&mut self) {
+        if !self.saccades || !time_reached(self.current_time, self.saccade_timer) {
+            return;
+        }
+
+        // Strip our previous offset unless the gaze has been moved since
+        let (base_x, base_y) = if self.saccades_own_target() {
+            (
+                self.eye_l_x_next - self.saccade_offset.0,
+                self.eye_l_y_next - self.saccade_offset.1,
+            )
+        } else {
+            (self.eye_l_x_next, self.eye_l_y_next)
+        };
+
+        let amp = self.saccade_amplitude as i32;
+        let dx = self.rng.gen_range(-amp..=amp);
+        let dy = self.rng.gen_range(-amp..=amp);
+        let x = (base_x + dx).clamp(0, self.get_constraint_x().max(0));
+        let y = (base_y + dy).clamp(0, self.get_constraint_y().max(0));
+
+        self.saccade_offset = (x - base_x, y - base_y);
+        self.saccade_target = (x, y);
+        self.eye_l_x_next = x;
+        self.eye_l_y_next = y;
+        self.saccade_timer = self
+            .current_time
+            .wrapping_add(self.rng.gen_range(SACCADE_MIN_MS..=SACCADE_MAX_MS));
+    }
+
+    /// Whether the gaze target is still the one the last saccade set
+    fn saccades_own_target(&self) -> bool {
+        (self.eye_l_x_next, self.eye_l_y_next) == self.saccade_target
+    }
+
+    /// Random extra delay of `0..variation` whole seconds, in milliseconds
+    fn random_delay(&mut self, variation: u64) -> u64 {
+        // No variation draws nothing (an empty range would panic)
+        if variation == 0 {
+            0
+        } else {
+            self.rng.gen_range(0..variation) * 1000
+        }
+    }
+
+    /// Random idle target in `0..=range`, biased towards the middle
+    fn idle_sample(&mut self, range: i32) -> i32 {
+        if self.idle_bias <= 0.0 {
+            return self.rng.gen_range(0..=range);
+        }
+
+        // Signed distance from the centre, squashed by the power curve
+        let s: f32 = self.rng.gen_range(-1.0..=1.0);
+        let s = s.signum() * s.abs().powf(1.0 + self.idle_bias);
+        ((s + 1.0) / 2.0 * range as f32).round() as i32
+    }
+
+    fn process_blink_events(&mut self) {
+        let closing = self.eye_l_height_next == self.closed_height
+            || self.eye_r_height_next == self.closed_height;
+        if closing {
+            self.blink_in_progress = true;
+            return;
+        }
+
+        let left_open =
+            self.eye_l_height_current >= self.eye_l_height_next + self.eye_l_height_offset;
+        let right_open = self.cyclops
+            || self.eye_r_height_current >= self.eye_r_height_next + self.eye_r_height_offset;
+        if self.blink_in_progress && left_open && right_open {
+            self.blink_in_progress = false;
+            self.on_blink_complete.call();
+        }
+    }
+
+    /// Shake amplitude per axis this frame, `None` for an axis at rest
+    ///
+    /// Running confused, laugh and shiver animations take over the axes
+    /// they shake (the largest amplitude wins when they overlap); other
+    /// axes follow the user's flicker settings. Animations never write
+    /// those settings, so re-triggering or overlapping them can't lose them.
+    fn flicker_amplitudes(&self) -> (Option<u32>, Option<u32>) {
+        fn drive(axis: &mut Option<u32>, amplitude: u32) {
+            *axis = Some(axis.map_or(amplitude, |a| a.max(amplitude)));
+        }
+
+        let (mut h, mut v) = (None, None);
+        if self.confused && !self.confused_toggle {
+            drive(&mut h, self.confused_amplitude);
+        }
+        if self.laugh && !self.laugh_toggle {
+            if self.laugh_running_style != LaughStyle::Vertical {
+                drive(&mut h, self.laugh_amplitude);
+            }
+            if self.laugh_running_style != LaughStyle::Horizontal {
+                drive(&mut v, self.laugh_amplitude);
+            }
+        }
+        if self.shiver && !self.shiver_toggle {
+            drive(&mut h, 2);
+            drive(&mut v, 1);
+        }
+
+        (
+            h.or(self.h_flicker.then_some(self.h_flicker_amplitude)),
+            v.or(self.v_flicker.then_some(self.v_flicker_amplitude)),
+        )
+    }
+
+    fn apply_flicker(&mut self) {
+        let (h, v) = self.flicker_amplitudes();
+
+        if let Some(amplitude) = h {
+            if self.h_flicker_alternate {
+                self.eye_l_x += amplitude as i32;
+                self.eye_r_x += amplitude as i32;
+            } else {
+                self.eye_l_x -= amplitude as i32;
+                self.eye_r_x -= amplitude as i32;
+            }
+            self.h_flicker_alternate = !self.h_flicker_alternate;
+        }
+
+        if let Some(amplitude) = v {
+            if self.v_flicker_alternate {
+                self.eye_l_y += amplitude as i32;
+                self.eye_r_y += amplitude as i32;
+            } else {
+                self.eye_l_y -= amplitude as i32;
+                self.eye_r_y -= amplitude as i32;
+            }
+            self.v_flicker_alternate = !self.v_flicker_alternate;
+        }
+
+        if h.is_some() || v.is_some() {
+            self.clamp_flicker();
+        }
+    }
+
+    /// Pin shaken eyes at the screen edge (minus the flicker margin)
+    ///
+    /// Both eyes move together so the spacing between them is kept.
+    fn clamp_flicker(&mut self) {
+        let margin = self.flicker_margin as i32;
+
+        let right = if self.cyclops {
+            self.eye_l_x + self.eye_l.width as i32
+        } else {
+            self.eye_r_x + self.eye_r.width as i32
+        };
+        let dx = if self.eye_l_x < margin {
+            margin - self.eye_l_x
+        } else if right > self.screen_width as i32 - margin {
+            self.screen_width as i32 - margin - right
+        } else {
+            0
+        };
+
+        let bottom = self.eye_l_y + self.eye_l_height_current as i32;
+        let dy = if self.eye_l_y < margin {
+            margin - self.eye_l_y
+        } else if bottom > self.screen_height as i32 - margin {
+            self.screen_height as i32 - margin - bottom
+        } else {
+            0
+        };
+
+        self.eye_l_x += dx;
+        self.eye_r_x += dx;
+        self.eye_l_y += dy;
+        self.eye_r_y += dy;
+    }
+
+    fn update_mood_transitions(&mut self) {
+        let covered = (self.eye_l_height_default as f32 * self.mood_intensity) as u32;
+        match self.mood {
+            Mood::Angry => {
+                self.eyelids_angry_height_next = covered;
+                self.eyelids_tired_height_next = 0;
+                self.eyelids_happy_bottom_offset_next = 0;
+                self.eyelids_skeptical_height_next = 0;
+                self.eye_l_scale_next = 1.0;
+                self.eye_r_scale_next = 1.0;
+            }
+            Mood::Happy => {
+                self.eyelids_happy_bottom_offset_next = covered;
+                self.eyelids_tired_height_next = 0;
+                self.eyelids_angry_height_next = 0;
+                self.eyelids_skeptical_height_next = 0;
+                self.eye_l_scale_next = 1.0;
+                self.eye_r_scale_next = 1.0;
+            }
+            Mood::Confuse => {
+                self.eyelids_tired_height_next = 0;
+                self.eyelids_angry_height_next = 0;
+                self.eyelids_happy_bottom_offset_next = 0;
+                self.eyelids_skeptical_height_next = 0;
+                self.eye_l_scale_next = 1.3;
+                self.eye_r_scale_next = 0.7;
+            }
+            Mood::Sad => {
+                // Eyes half-closed and looking down
+                self.eyelids_tired_height_next = covered;
+                self.eyelids_angry_height_next = 0;
+                self.eyelids_happy_bottom_offset_next = 0;
+                self.eyelids_skeptical_height_next = 0;
+                self.eye_l_scale_next = 1.0;
+                self.eye_r_scale_next = 1.0;
+                // Move eyes downward
+                let (_, max_y) = self.gaze_range_y();
+                self.eye_l_y_next = max_y;
+                self.eye_r_y_next = max_y;
+            }
+            Mood::Surprise => {
+                // Eyes wide open (enlarged)
+                self.eyelids_tired_height_next = 0;
+                self.eyelids_angry_height_next = 0;
+                self.eyelids_happy_bottom_offset_next = 0;
+                self.eyelids_skeptical_height_next = 0;
+                self.eye_l_scale_next = 1.3;
+                self.eye_r_scale_next = 1.3;
+            }
+            Mood::Loading => {
+                // Eyes normal, animation handled separately
+                self.eyelids_tired_height_next = 0;
+                self.eyelids_angry_height_next = 0;
+                self.eyelids_happy_bottom_offset_next = 0;
+                self.eyelids_skeptical_height_next = 0;
+                self.eye_l_scale_next = 1.0;
+                self.eye_r_scale_next = 1.0;
+            }
+            Mood::Skeptical => {
+                // Left eyelid lowered partway, right eye fully open
+                self.eyelids_skeptical_height_next = self.eye_l_height_default / 3;
+                self.eyelids_tired_height_next = 0;
+                self.eyelids_angry_height_next = 0;
+                self.eyelids_happy_bottom_offset_next = 0;
+                self.eye_l_scale_next = 1.0;
+                self.eye_r_scale_next = 1.0;
+            }
+            Mood::Default => {
+                self.eyelids_tired_height_next = 0;
+                self.eyelids_angry_height_next = 0;
+                self.eyelids_happy_bottom_offset_next = 0;
+                self.eyelids_skeptical_height_next = 0;
+                self.eye_l_scale_next = 1.0;
+                self.eye_r_scale_next = 1.0;
+            }
+        }
+
+        // Tween eyelid values
+        self.eyelids_tired_height =
+            self.tween_u32(self.eyelids_tired_height, self.eyelids_tired_height_next);
+        self.eyelids_angry_height =
+            self.tween_u32(self.eyelids_angry_height, self.eyelids_angry_height_next);
+        self.eyelids_happy_bottom_offset = self.tween_u32(
+            self.eyelids_happy_bottom_offset,
+            self.eyelids_happy_bottom_offset_next,
+        );
+        self.eyelids_skeptical_height = self.tween_u32(
+            self.eyelids_skeptical_height,
+            self.eyelids_skeptical_height_next,
+        );
+
+        // Tween eye scales for Confuse mood
+        let k = self.tween_step.unwrap_or(0.5);
+        self.eye_l_scale += (self.eye_l_scale_next - self.eye_l_scale) * k;
+        self.eye_r_scale += (self.eye_r_scale_next - self.eye_r_scale) * k;
+
+        // Pupils dilate with excitement and constrict with anger
+        let dilation = match self.mood {
+            Mood::Happy | Mood::Surprise => PUPIL_DILATED,
+            Mood::Angry => PUPIL_CONSTRICTED,
+            _ => 1.0,
+        };
+        let target = (self.pupil_dilation * dilation).min(1.0);
+        self.pupil_size += (target - self.pupil_size) * k;
+    }
+
+    /// Pupil radius in pixels for an eye occupying `rect`
+    fn pupil_radius(&self, rect: Rect) -> u32 {
+        // Leave at least a 1px rim of eye around the pupil
+        let max = (rect.w.min(rect.h) / 2).saturating_sub(1);
+        ((self.pupil_size * max as f32).round() as u32).min(max)
+    }
+
+    /// Every visible eye, left to right
+    ///
+    /// Middle eyes copy the left eye, Confuse scale included, so each draw
+    /// step treats them like any other eye. During a cyclops transition the
+    /// collapsing eye keeps its side's features until it's gone.
+    fn drawn_eyes(&self) -> Vec<DrawnEye<'_>> {
+        let (left, right) = self.eye_rects();
+        let Some(right) = right else {
+            let geometry = match self.cyclops_eye {
+                Side::Left => &self.eye_l,
+                Side::Right => &self.eye_r,
+            };
+            return vec![DrawnEye {
+                rect: left,
+                geometry,
+                role: EyeRole::Center,
+            }];
+        };
+
+        let middle = (1..=self.middle_eye_count() as i32).map(|i| DrawnEye {
+            rect: Rect {
+                x: left.x + i * self.eye_stride(),
+                ..left
+            },
+            geometry: &self.eye_l,
+            role: EyeRole::Center,
+        });
+        std::iter::once(DrawnEye {
+            rect: left,
+            geometry: &self.eye_l,
+            role: EyeRole::Left,
+        })
+        .chain(middle)
+        .chain(std::iter::once(DrawnEye {
+            rect: right,
+            geometry: &self.eye_r,
+            role: EyeRole::Right,
+        }))
+        .collect()
+    }
+
+    fn draw_pupils(&self, canvas: &mut impl Canvas, eyes: &[DrawnEye]) {
+        let (gaze_x, gaze_y) = self.gaze();
+        for &DrawnEye { rect, .. } in eyes {
+            // Iris disc, if any, and the pupil inside it
+            let (iris, r) = match self.iris {
+                Some((inner, outer, color)) => {
+                    let max = (rect.w.min(rect.h) / 2).saturating_sub(1);
+                    let outer = outer.min(max);
+                    let inner = if self.pupils {
+                        self.pupil_radius(rect)
+                    } else {
+                        inner
+                    };
+                    (Some((outer, color)), inner.min(outer.saturating_sub(1)))
+                }
+                None => (None, self.pupil_radius(rect)),
+            };
+            let extent = iris.map_or(r, |(outer, _)| outer);
+            if extent == 0 {
+                continue;
+            }
+            // Shift towards the gaze direction, staying inside the eye
+            let room_x = (rect.w / 2).saturating_sub(extent + 1) as f32;
+            let room_y = (rect.h / 2).saturating_sub(extent + 1) as f32;
+            let cx = rect.x + rect.w as i32 / 2 + (gaze_x * room_x).round() as i32;
+            let cy = rect.y + rect.h as i32 / 2 + (gaze_y * room_y).round() as i32;
+            let mut disc = |r: u32, color| {
+                canvas.rounded_rect(cx - r as i32, cy - r as i32, r * 2, r * 2, r, color);
+            };
+            if let Some((outer, color)) = iris {
+                disc(outer, color);
+            }
+            if r > 0 {
+                disc(r, self.bg());
+            }
+        }
+    }
+
+    fn draw_eyelids(&self, canvas: &mut impl Canvas, eyes: &[DrawnEye]) {
+        // Skeptical: straight eyelid on the left (or only) eye.
+        // Drawn whenever lowered so it also tweens out after a mood change.
+        if self.eyelids_skeptical_height > 0 {
+            let rect = eyes[0].rect;
+            canvas.rounded_rect(
+                rect.x - 1,
+                rect.y - 1,
+                rect.w + 2,
+                self.eyelids_skeptical_height + 1,
+                0,
+                self.bg(),
+            );
+        }
+
+        for eye in eyes {
+            let Rect { x, y, w, h } = eye.rect;
+            let (top, right) = (y - 1, x + w as i32);
+            match self.mood {
+                // Sad/Sleepy eyelids: outer corners lowered
+                Mood::Sad => {
+                    let bottom = y + self.eyelids_tired_height as i32 - 1;
+                    match eye.role {
+                        EyeRole::Left => canvas.triangle(x, top, right, top, x, bottom, self.bg()),
+                        EyeRole::Right => {
+                            canvas.triangle(x, top, right, top, right, bottom, self.bg())
+                        }
+                        EyeRole::Center => self.draw_center_tired_eyelid(canvas, eye.rect),
+                    }
+                }
+                // Angry eyelids: inner corners lowered
+                Mood::Angry => {
+                    let bottom = y + self.eyelids_angry_height as i32 - 1;
+                    match eye.role {
+                        EyeRole::Left => {
+                            canvas.triangle(x, top, right, top, right, bottom, self.bg())
+                        }
+                        EyeRole::Right => canvas.triangle(x, top, right, top, x, bottom, self.bg()),
+                        EyeRole::Center => self.draw_center_angry_eyelid(canvas, eye.rect),
+                    }
+                }
+                Mood::Happy => {
+                    let top = y + h as i32 - self.eyelids_happy_bottom_offset as i32 + 1;
+                    self.draw_happy_eyelid(canvas, eye.rect, top, eye.geometry);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Happy cut-out for the eye in `rect`, reaching up to `top`
+    fn draw_happy_eyelid(&self, canvas: &mut impl Canvas, rect: Rect, top: i32, eye: &EyeGeometry) {
+        let Rect {
+            x,
+            w: width,
+            h: height,
+            ..
+        } = rect;
+        match self.happy_style {
+            HappyStyle::Flat => {
+                canvas.rounded_rect_xy(
+                    x - 1,
+                    top,
+                    width + 2,
+                    height,
+                    eye.border_radius,
+                    self.radius_y(eye.border_radius),
+                    self.bg(),
+                );
+            }
+            HappyStyle::Curved => {
+                // An ellipse half again as wide as the eye: its top meets
+                // the flat cut in the middle and falls away to the sides
+                let (cut_width, cut_height) = (width + width / 2 + 2, height * 2);
+                canvas.rounded_rect_xy(
+                    x - 1 - (width / 4) as i32,
+                    top,
+                    cut_width,
+                    cut_height,
+                    cut_width,
+                    cut_height,
+                    self.bg(),
+                );
+            }
+        }
+    }
+
+    /// Symmetric sad eyelid (both outer corners lowered) for a centre eye
+    fn draw_center_tired_eyelid(&self, canvas: &mut impl Canvas, rect: Rect) {
+        let (x, half) = (rect.x, rect.w as i32 / 2);
+        let top = rect.y - 1;
+        let bottom = rect.y + self.eyelids_tired_height as i32 - 1;
+        canvas.triangle(x, top, x + half, top, x, bottom, self.bg());
+        canvas.triangle(
+            x + half,
+            top,
+            x + rect.w as i32,
+            top,
+            x + rect.w as i32,
+            bottom,
+            self.bg(),
+        );
+    }
+
+    /// Symmetric angry eyelid (V shape) for a centre eye
+    fn draw_center_angry_eyelid(&self, canvas: &mut impl Canvas, rect: Rect) {
+        let (x, half) = (rect.x, rect.w as i32 / 2);
+        let top = rect.y - 1;
+        let bottom = rect.y + self.eyelids_angry_height as i32 - 1;
+        canvas.triangle(x, top, x + half, top, x + half, bottom, self.bg());
+        canvas.triangle(
+            x + half,
+            top,
+            x + rect.w as i32,
+            top,
+            x + half,
+            bottom,
+            self.bg(),
+        );
+    }
+
+    /// Effective foreground color, honoring inversion
+    fn fg(&self) -> u8 {
+        if self.inverted {
+            self.bg_color
+        } else {
+            self.fg_color
+        }
+    }
+
+    /// Effective background color, honoring inversion
+    fn bg(&self) -> u8 {
+        if self.inverted {
+            self.fg_color
+        } else {
+            self.bg_color
+        }
+    }
+
+    /// Dimmed copies of the eyes, offset down-right
+    fn draw_shadow(&self, canvas: &mut impl Canvas, eyes: &[DrawnEye]) {
+        let (bg, fg) = (self.bg() as i32, self.fg() as i32);
+        let color = (bg + (fg - bg) * self.shadow_intensity as i32 / 255) as u8;
+        let offset = self.shadow_offset;
+
+        for &DrawnEye { rect, geometry, .. } in eyes {
+            canvas.rounded_rect_corners(
+                rect.x + offset,
+                rect.y + offset,
+                rect.w,
+                rect.h,
+                self.corner_radii(geometry),
+                color,
+            );
+        }
+    }
+
+    /// Vertical corner radius corrected for the pixel aspect ratio
+    fn radius_y(&self, radius: u32) -> u32 {
+        (radius as f32 * self.pixel_aspect).round() as u32
+    }
+
+    /// Per-corner `(x, y)` radii of an eye, in canvas order
+    fn corner_radii(&self, eye: &EyeGeometry) -> [(u32, u32); 4] {
+        let c = eye.corners();
+        [c.top_left, c.top_right, c.bottom_left, c.bottom_right].map(|r| (r, self.radius_y(r)))
+    }
+
+    fn update_sweat(&mut self) {
+        let resets =
+            self.sweat_drops
+                .update(self.frame_ms as f32, self.sweat_speed, self.sweat_gravity);
+
+        for i in resets {
+            self.sweat_drops.0[i].reset(self.screen_width);
+        }
+    }
+
+    fn draw_sweat(&self, canvas: &mut impl Canvas) {
+        for drop in self.sweat_drops.0.iter() {
+            let (x, y, w, h) = drop.params();
+            canvas.rounded_rect_xy(
+                x,
+                y,
+                w,
+                h,
+                self.sweat_radius,
+                self.radius_y(self.sweat_radius),
+                self.sweat_color.unwrap_or(self.fg()),
+            );
+        }
+    }
+
+    /// Scanner bar X position and direction (1 = moving right)
+    fn scanner_x(&self) -> (i32, i32) {
+        let max_x = (self.screen_width as i32 - SCANNER_WIDTH as i32).max(0) as u64;
+        let phase = self.current_time % (2 * SCANNER_SWEEP_MS);
+        if phase < SCANNER_SWEEP_MS {
+            ((phase * max_x / SCANNER_SWEEP_MS) as i32, 1)
+        } else {
+            (
+                ((2 * SCANNER_SWEEP_MS - phase) * max_x / SCANNER_SWEEP_MS) as i32,
+                -1,
+            )
+        }
+    }
+
+    fn draw_scanner(&self, canvas: &mut impl Canvas) {
+        let (x, dir) = self.scanner_x();
+        let width = SCANNER_WIDTH as i32;
+        let top = (self.screen_height as i32 - self.eye_l_height_default as i32) / 2;
+        let bottom = top + self.eye_l_height_default as i32 - 1;
+        let (fg, bg) = (self.fg() as i32, self.bg() as i32);
+
+        // Trail fades from the foreground towards the background, oldest first
+        for step in (0..=SCANNER_TRAIL).rev() {
+            let color = bg + (fg - bg) * (SCANNER_TRAIL + 1 - step) / (SCANNER_TRAIL + 1);
+            let center = x + width / 2 - dir * step * width;
+            canvas.line(center, top, center, bottom, SCANNER_WIDTH, color as u8);
+        }
+    }
+
+    /// Centre of the thinking dot at the current time
+    fn thinking_dot(&self) -> (i32, i32) {
+        let radius = THINKING_ORBIT_RADIUS as f32;
+        let margin = THINKING_ORBIT_RADIUS as i32 + THINKING_DOT_SIZE as i32;
+
+        // Orbit centre: above the middle of the row, kept on screen
+        let right = if self.cyclops {
+            self.eye_l_x + self.eye_l.width as i32
+        } else {
+            self.eye_r_x + self.eye_r.width as i32
+        };
+        let cx = ((self.eye_l_x + right) / 2)
+            .clamp(margin, (self.screen_width as i32 - margin).max(margin));
+        let cy = (self.eye_l_y / 2).clamp(margin, (self.screen_height as i32 - margin).max(margin));
+
+        let angle = (self.current_time % self.thinking_period) as f32 / self.thinking_period as f32
+            * std::f32::consts::TAU;
+        (
+            cx + (angle.cos() * radius).round() as i32,
+            cy + (angle.sin() * radius).round() as i32,
+        )
+    }
+
+    fn draw_eyebrows(&self, canvas: &mut impl Canvas, eyes: &[DrawnEye]) {
+        // Gap, tilt and thickness scale with the eye size
+        let height = self.eye_l_height_default as i32;
+        let gap = height / 4;
+        let tilt = height / 6;
+        let thickness = (height / 12).max(1) as u32;
+
+        // Vertical offset of the inner and outer end (positive = down)
+        let (inner, outer) = match self.mood {
+            Mood::Angry => (tilt, 0),
+            Mood::Sad => (-tilt, 0),
+            Mood::Surprise => (-gap, -gap),
+            _ => (0, 0),
+        };
+
+        for eye in eyes {
+            let x0 = eye.rect.x;
+            let x1 = eye.rect.x + eye.rect.w as i32 - 1;
+            let y = eye.rect.y - gap;
+            match eye.role {
+                EyeRole::Left => canvas.line(x0, y + outer, x1, y + inner, thickness, self.fg()),
+                EyeRole::Right => canvas.line(x0, y + inner, x1, y + outer, thickness, self.fg()),
+                EyeRole::Center => {
+                    // Inner end is the middle, outer ends on both sides
+                    let mid = (x0 + x1) / 2;
+                    for x in [x0, x1] {
+                        canvas.line(x, y + outer, mid, y + inner, thickness, self.fg());
+                    }
+                }
+            }
+        }
+    }
+
+    fn apply_glitch(&mut self, img: &mut GrayImage) {
+        let intensity = self.glitch_intensity as u32;
+        if intensity == 0 {
+            return;
+        }
+
+        let width = img.width();
+        let height = img.height();
+
+        // Shift random scanlines sideways, filling the gap with background
+        let max_shift = 1 + (intensity * 8 / 255) as i32;
+        let rows = 1 + intensity * height / 4 / 255;
+        let mut line = vec![self.bg(); width as usize];
+        for _ in 0..rows {
+            let y = self.rng.gen_range(0..height);
+            let shift = self.rng.gen_range(-max_shift..=max_shift);
+            for (x, value) in line.iter_mut().enumerate() {
+                *value = img.get_pixel(x as u32, y)[0];
+            }
+            for x in 0..width as i32 {
+                let src = x - shift;
+                let value = if src >= 0 && src < width as i32 {
+                    line[src as usize]
+                } else {
+                    self.bg()
+                };
+                img.put_pixel(x as u32, y, image::Luma([value]));
+            }
+        }
+
+        // Invert scattered pixels
+        let flips = intensity * width * height / (255 * 64);
+        for _ in 0..flips {
+            let x = self.rng.gen_range(0..width);
+            let y = self.rng.gen_range(0..height);
+            let value = img.get_pixel(x, y)[0];
+            img.put_pixel(x, y, image::Luma([255 - value]));
+        }
+    }
+
+    /// Short lines converging on the outer corner of each eye
+    fn draw_wince_wrinkles(&self, canvas: &mut impl Canvas, eyes: &[DrawnEye]) {
+        // Outer corners of the row; a lone eye has both
+        let (Some(left), Some(right)) = (eyes.first(), eyes.last()) else {
+            return;
+        };
+        let (left, right) = (left.rect, right.rect);
+        let mut wrinkle = |tip_x: i32, cy: i32, dir: i32| {
+            let end_x = tip_x + dir * WINCE_LINE;
+            let spread = WINCE_LINE / 2;
+            canvas.line(end_x, cy - spread, tip_x, cy, 1, self.fg());
+            canvas.line(end_x, cy + spread, tip_x, cy, 1, self.fg());
+        };
+        wrinkle(left.x - 2, left.y + left.h as i32 / 2, -1);
+        wrinkle(
+            right.x + right.w as i32 + 1,
+            right.y + right.h as i32 / 2,
+            1,
+        );
+    }
+
+    fn draw_loading(&self, canvas: &mut impl Canvas, eyes: &[DrawnEye]) {
+        // Blue flickering effect during loading
+        let cycle = (self.current_time / 200) % 4;
+        let color = match cycle {
+            0 => 255,
+            1 => 200,
+            2 => 100,
+            _ => 50,
+        };
+
+        // Draw loading indicator in center of each eye
+        let indicator_size = 4i32;
+        for &DrawnEye { rect, .. } in eyes {
+            let center_x = rect.x + rect.w as i32 / 2;
+            let center_y = rect.y + rect.h as i32 / 2;
+            canvas.rounded_rect(
+                center_x - indicator_size / 2,
+                center_y - indicator_size / 2,
+                indicator_size as u32,
+                indicator_size as u32,
+                2,
+                color,
+            );
+        }
+    }
+}
+
+/// Where an eye sits in the row, which decides how mood features face
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EyeRole {
+    Left,
+    Right,
+    /// Middle eyes and the lone cyclops eye: symmetric features
+    Center,
+}
+
+/// One eye as drawn this frame
+#[derive(Clone, Copy)]
+struct DrawnEye<'a> {
+    rect: Rect,
+    geometry: &'a EyeGeometry,
+    role: EyeRole,
+}
+
+/// Linear, time-based transition of a height toward its target
+#[derive(Clone, Copy, Default)]
+struct TimedTween {
+    from: u32,
+    to: u32,
+    since: u64,
+}
+
+impl TimedTween {
+    /// Height at `now`, restarting from `current` whenever the target changes
+    ///
+    /// A change of `full` pixels takes `close_ms` when shrinking and
+    /// `open_ms` when growing.
+    fn step(
+        &mut self,
+        current: u32,
+        target: u32,
+        now: u64,
+        full: u32,
+        close_ms: u64,
+        open_ms: u64,
+    ) -> u32 {
+        if target != self.to {
+            *self = Self {
+                from: current,
+                to: target,
+                since: now,
+            };
+        }
+
+        let ms = if self.to < self.from {
+            close_ms
+        } else {
+            open_ms
+        };
+        if ms == 0 {
+            return self.to;
+        }
+        let travel = (elapsed_since(now, self.since) * full as u64 / ms) as u32;
+        if self.to < self.from {
+            self.from.saturating_sub(travel).max(self.to)
+        } else {
+            (self.from + travel).min(self.to)
+        }
+    }
+}
+
+/// Curve-based transition of a value toward its target
+#[derive(Clone, Copy, Default)]
+struct EasedTween {
+    from: i32,
+    // None until the first step
+    to: Option<i32>,
+    since: u64,
+}
+
+impl EasedTween {
+    /// Value at `now`, restarting from `current` whenever the target changes
+    ///
+    /// A restart counts as already one frame (`frame_ms`) old so values
+    /// keep moving even if the target changes every frame.
+    fn step(&mut self, current: i32, target: i32, now: u64, frame_ms: u64, easing: Easing) -> i32 {
+        if self.to != Some(target) {
+            *self = Self {
+                from: current,
+                to: Some(target),
+                since: now.wrapping_sub(frame_ms),
+            };
+        }
+
+        let t = elapsed_since(now, self.since) as f32 / EASING_MS as f32;
+        let eased = easing.apply(t);
+        self.from + ((target - self.from) as f32 * eased).round() as i32
+    }
+}
+
+/// Optional user callback
+///
+/// Boxed closures can't be cloned, so a cloned `RoboEyes` gets an empty slot.
+/// Callbacks are `Send` so `RoboEyes` can still move to a render thread.
+#[derive(Default)]
+struct Callback(Option<Box<dyn FnMut() + Send>>);
+
+impl Callback {
+    fn call(&mut self) {
+        if let Some(callback) = self.0.as_mut() {
+            callback();
+        }
+    }
+}
+
+impl Clone for Callback {
+    fn clone(&self) -> Self {
+        Self(None)
+    }
+}
+
+impl Default for RoboEyes {
+    fn default() -> Self {
+        Self::new(128, 64)
+    }
+}
+
+/// Lub-dub pulse shape over one beat (`phase` 0.0-1.0)
+///
+/// A full-height bump early in the beat, a smaller one shortly after, and
+/// rest for the remainder.
+fn heartbeat_wave(phase: f32) -> f32 {
+    let bump = |centre: f32| (-((phase - centre) / 0.04).powi(2)).exp();
+    bump(0.1) + 0.6 * bump(0.3)
+}
+
+/// Move `current` halfway toward `next`, snapping once within 1px
+///
+/// Plain integer averaging truncates and never closes the last 1px gap.
+fn tween_i32(current: i32, next: i32) -> i32 {
+    if (next - current).abs() <= 1 {
+        next
+    } else {
+        (current + next) / 2
+    }
+}
+
+/// Move `current` toward `next` by `factor` of the gap, at least 1px per call
+fn tween_i32_by(current: i32, next: i32, factor: f32) -> i32 {
+    let diff = next - current;
+    if diff.abs() <= 1 {
+        return next;
+    }
+    let step = (diff as f32 * factor).round() as i32;
+    if step == 0 {
+        current + diff.signum()
+    } else {
+        current + step
+    }
+}
+
+/// Whether `now` has reached `deadline`
+///
+/// Compares the wrapping difference, so deadlines scheduled just before
+/// the clock wraps past `u64::MAX` still fire after it.
+fn time_reached(now: u64, deadline: u64) -> bool {
+    (now.wrapping_sub(deadline) as i64) >= 0
+}
+
+/// Milliseconds from `since` to `now` across a wrap, 0 if `since` is later
+fn elapsed_since(now: u64, since: u64) -> u64 {
+    if time_reached(now, since) {
+        now.wrapping_sub(since)
+    } else {
+        0
+    }
+}
+
+/// [`BotEyesError::BufferSize`] for a flat buffer of `len` elements
+fn buffer_len_error(expected: (u32, u32), len: usize) -> BotEyesError {
+    BotEyesError::BufferSize {
+        expected,
+        actual: (u32::try_from(len).unwrap_or(u32::MAX), 1),
+    }
+}
+
+/// Expand a gray level to an opaque `0xAARRGGBB` word
+fn gray_to_argb(value: u8) -> u32 {
+    let v = value as u32;
+    0xFF00_0000 | (v << 16) | (v << 8) | v
+}
+
+/// Blend from `a` (at 0.0) to `b` (at 1.0)
+fn lerp_rgb(a: Rgb<u8>, b: Rgb<u8>, t: f32) -> Rgb<u8> {
+    Rgb(std::array::from_fn(|i| {
+        (a[i] as f32 + (b[i] as f32 - a[i] as f32) * t).round() as u8
+    }))
+}
+
+use image::{GrayImage, Luma, Rgb, RgbImage};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let eyes = RoboEyes::new(128, 64);
+        assert_eq!(eyes.screen_width, 128);
+        assert_eq!(eyes.screen_height, 64);
+    }
+
+    #[test]
+    fn test_set_mood() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_mood(Mood::Happy);
+        assert_eq!(eyes.mood, Mood::Happy);
+    }
+
+    #[test]
+    fn test_set_position() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_position(Position::NorthEast);
+    }
+
+    #[test]
+    fn test_draw_eyes() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_mood(Mood::Default);
+        eyes.open();
+        let img = eyes.draw_eyes(1000);
+        assert_eq!(img.width(), 128);
+        assert_eq!(img.height(), 64);
+    }
+
+    #[test]
+    fn test_blink() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.blink();
+    }
+
+    #[test]
+    fn test_confused_restores_flicker() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_h_flicker(true, 3);
+        eyes.anim_confused();
+
+        eyes.draw_eyes(0);
+        assert_eq!(eyes.flicker_amplitudes(), (Some(20), None));
+
+        eyes.draw_eyes(600);
+        assert!(!eyes.confused);
+        assert_eq!(eyes.flicker_amplitudes(), (Some(3), None));
+
+        // Re-triggering mid-run, or overlapping with shiver, still ends on
+        // the user's settings
+        eyes.set_v_flicker(true, 4);
+        eyes.anim_confused();
+        eyes.anim_laugh();
+        eyes.draw_eyes(700);
+        eyes.anim_confused();
+        eyes.anim_laugh();
+        eyes.anim_shiver(300);
+        eyes.draw_eyes(900);
+        assert_eq!(eyes.flicker_amplitudes(), (Some(20), Some(5)));
+        eyes.draw_eyes(1500);
+        assert!(!eyes.confused && !eyes.laugh && !eyes.shiver);
+        assert_eq!(eyes.flicker_amplitudes(), (Some(3), Some(4)));
+        assert!(eyes.h_flicker && eyes.v_flicker);
+    }
+
+    #[test]
+    fn test_laugh_duration() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_laugh_duration(2000);
+        eyes.anim_laugh();
+
+        // Uneven frame spacing must not end the animation early
+        for t in [0, 16, 400, 1100, 1999] {
+            eyes.draw_eyes(t);
+            assert!(eyes.flicker_amplitudes().1.is_some(), "still at {t}ms");
+        }
+
+        eyes.draw_eyes(2000);
+        assert_eq!(eyes.flicker_amplitudes(), (None, None));
+        assert!(!eyes.laugh);
+
+        // A one-off duration leaves the configured one alone
+        eyes.set_laugh_duration(500);
+        eyes.anim_laugh_for(1500);
+        eyes.draw_eyes(3000);
+        eyes.draw_eyes(4000);
+        assert!(eyes.laugh);
+        eyes.draw_eyes(4500);
+        assert!(!eyes.laugh);
+        assert_eq!(eyes.laugh_duration, 500);
+
+        eyes.anim_laugh();
+        eyes.draw_eyes(5000);
+        eyes.draw_eyes(5500);
+        assert!(!eyes.laugh);
+
+        eyes.anim_confused_for(1500);
+        eyes.draw_eyes(6000);
+        eyes.draw_eyes(7000);
+        assert!(eyes.confused);
+        eyes.draw_eyes(7500);
+        assert!(!eyes.confused);
+        assert_eq!(eyes.confused_duration, 500);
+    }
+
+    #[test]
+    fn test_tween_reaches_target() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_space_between(11).unwrap();
+        eyes.set_mood(Mood::Angry);
+        eyes.set_position(Position::East);
+        for t in 0..30 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        assert_eq!(eyes.eye_l_x, eyes.eye_l_x_next);
+        assert_eq!(eyes.eye_l_y, eyes.eye_l_y_next);
+        assert_eq!(eyes.eye_r_x, eyes.eye_r_x_next);
+        assert_eq!(eyes.eye_l_height_current, eyes.eye_l_height_default);
+        assert_eq!(eyes.eyelids_angry_height, eyes.eyelids_angry_height_next);
+
+        // Off-by-one gaps that plain averaging would never close
+        assert_eq!(tween_i32(9, 10), 10);
+        assert_eq!(tween_i32(-3, -4), -4);
+        assert_eq!(RoboEyes::default().tween_u32(35, 36), 36);
+    }
+
+    #[test]
+    fn test_default() {
+        let eyes = RoboEyes::default();
+        assert_eq!(eyes.screen_width, 128);
+        assert_eq!(eyes.screen_height, 64);
+    }
+
+    #[test]
+    fn test_clone_mid_animation() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Angry);
+        eyes.set_position(Position::SouthWest);
+        eyes.anim_confused();
+        for t in 0..5 {
+            eyes.draw_eyes(t * 20);
+        }
+        eyes.blink();
+
+        let mut fork = eyes.clone();
+        for t in 5..40 {
+            assert_eq!(eyes.draw_eyes(t * 20), fork.draw_eyes(t * 20));
+        }
+    }
+
+    #[test]
+    fn test_pause_resume() {
+        // Draw a frame and report whether it started a blink
+        fn step(eyes: &mut RoboEyes, t: u64) -> (GrayImage, bool) {
+            let was_open = eyes.eye_l_height_next != 1;
+            let img = eyes.draw_eyes(t);
+            (img, was_open && eyes.eye_l_height_next == 1)
+        }
+
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_autoblinker(true, 2, 1);
+
+        // First autoblink fires on the first frame
+        let mut blinks = 0;
+        for t in (0..1000).step_by(20) {
+            blinks += step(&mut eyes, t).1 as u32;
+        }
+        assert_eq!(blinks, 1);
+
+        eyes.pause();
+        assert!(eyes.is_paused());
+        let (frozen, _) = step(&mut eyes, 1000);
+        for t in (1020..11000).step_by(20) {
+            let (img, blinked) = step(&mut eyes, t);
+            assert_eq!(img, frozen);
+            assert!(!blinked);
+        }
+        assert_eq!(eyes.current_time, 980);
+
+        // 10s later: no catch-up blink, clock continues from before the pause
+        eyes.resume();
+        for t in (11000..11960).step_by(20) {
+            blinks += step(&mut eyes, t).1 as u32;
+        }
+        assert_eq!(blinks, 1);
+        assert!(eyes.current_time < 2000);
+
+        for t in (11960..12200).step_by(20) {
+            blinks += step(&mut eyes, t).1 as u32;
+        }
+        assert_eq!(blinks, 2);
+    }
+
+    #[test]
+    fn test_reset() {
+        let config = RoboEyesConfig::default().with_eye_width(30);
+        let mut eyes = RoboEyes::new_with_config(128, 64, config.clone());
+        eyes.set_mood(Mood::Happy);
+        eyes.set_size(20, 44).unwrap();
+        eyes.set_border_radius(2, 14);
+        eyes.set_space_between(3).unwrap();
+        eyes.set_position(Position::SouthEast);
+        eyes.set_cyclops(true);
+        eyes.set_curiosity(true);
+        eyes.set_sweat(true);
+        eyes.set_h_flicker(true, 4);
+        eyes.set_autoblinker(true, 1, 1);
+        eyes.anim_laugh();
+        for t in 0..20 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        eyes.reset();
+        let mut fresh = RoboEyes::new_with_config(128, 64, config);
+        eyes.open();
+        fresh.open();
+        for t in 0..20 {
+            assert_eq!(eyes.draw_eyes(t * 20), fresh.draw_eyes(t * 20));
+        }
+    }
+
+    #[test]
+    fn test_chained_setters() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_mood(Mood::Happy)
+            .set_size_unchecked(30, 30)
+            .set_border_radius(6, 6)
+            .set_space_between_unchecked(12)
+            .set_position(Position::East)
+            .set_cyclops(true)
+            .set_curiosity(true)
+            .set_sweat(true)
+            .set_autoblinker(true, 2, 2)
+            .set_idle_mode(true, 2, 2, 50, 50)
+            .open();
+
+        assert_eq!(eyes.mood, Mood::Happy);
+        assert_eq!(eyes.eye_l.width, 30);
+        assert_eq!(eyes.space_between, 12);
+        assert!(eyes.cyclops && eyes.curious && eyes.sweat);
+        assert!(eyes.autoblinker && eyes.idle);
+        assert!(eyes.eye_l_open);
+    }
+
+    #[test]
+    fn test_skeptical_mood() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Skeptical);
+        let mut img = eyes.draw_eyes(0);
+        for t in 1..20 {
+            img = eyes.draw_eyes(t * 20);
+        }
+
+        // Row just inside the top of each eye: covered on the left only
+        let y = (eyes.eye_l_y + 4) as u32;
+        let left_x = (eyes.eye_l_x + eyes.eye_l.width as i32 / 2) as u32;
+        let right_x = (eyes.eye_r_x + eyes.eye_r.width as i32 / 2) as u32;
+        assert_eq!(img.get_pixel(left_x, y)[0], BGCOLOR);
+        assert_eq!(img.get_pixel(right_x, y)[0], MAINCOLOR);
+
+        // Lower half of both eyes stays open
+        let y = (eyes.eye_l_y + eyes.eye_l_height_current as i32 - 6) as u32;
+        assert_eq!(img.get_pixel(left_x, y)[0], MAINCOLOR);
+        assert_eq!(img.get_pixel(right_x, y)[0], MAINCOLOR);
+
+        // Tweens back out after leaving the mood
+        eyes.set_mood(Mood::Default);
+        eyes.draw_eyes(400);
+        assert!(eyes.eyelids_skeptical_height > 0);
+        for t in 21..40 {
+            eyes.draw_eyes(t * 20);
+        }
+        assert_eq!(eyes.eyelids_skeptical_height, 0);
+    }
+
+    #[test]
+    fn test_glitch() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_seed(7);
+        eyes.open();
+        for t in 0..20 {
+            eyes.draw_eyes(t * 20);
+        }
+        // Static scene renders identically without glitch
+        assert_eq!(eyes.draw_eyes(400), eyes.draw_eyes(420));
+
+        eyes.set_glitch(true, 255);
+        let a = eyes.draw_eyes(440);
+        let b = eyes.draw_eyes(460);
+        assert_ne!(a, b);
+        assert_eq!(a.dimensions(), (128, 64));
+    }
+
+    #[test]
+    fn test_breathing() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for t in 0..20 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        eyes.set_breathing(true, 4, 1000);
+        let mut min = u32::MAX;
+        let mut max = 0;
+        for t in (1000..=2000).step_by(20) {
+            eyes.draw_eyes(t);
+            min = min.min(eyes.eye_l_height_current);
+            max = max.max(eyes.eye_l_height_current);
+        }
+        assert!(max - min >= 6, "height range {min}..{max}");
+        assert!(
+            eyes.eye_l_height_current
+                .abs_diff(eyes.eye_l_height_default)
+                <= 2
+        );
+
+        // A blink still closes the eye fully
+        eyes.blink();
+        eyes.draw_eyes(2020);
+        for t in 102..110 {
+            eyes.draw_eyes(t * 20);
+            if eyes.eye_l_height_current <= 1 {
+                return;
+            }
+        }
+        panic!("blink did not close the eye while breathing");
+    }
+
+    #[test]
+    fn test_sweat_count() {
+        let mut eyes = RoboEyes::new(128, 64);
+        assert_eq!(eyes.sweat_drops.0.len(), 3);
+
+        eyes.set_sweat_count(6).set_sweat(true);
+        for t in 0..100 {
+            eyes.draw_eyes(t * 20);
+        }
+        assert_eq!(eyes.sweat_drops.0.len(), 6);
+    }
+
+    #[test]
+    fn test_sweat_small_screen() {
+        let mut eyes = RoboEyes::new(32, 32);
+        eyes.set_sweat(true);
+        for t in 0..200 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        eyes.set_sweat_region(10, 8);
+        for t in 200..400 {
+            eyes.draw_eyes(t * 20);
+            for drop in &eyes.sweat_drops.0 {
+                let (_, y, _, _) = drop.params();
+                assert!((10..=19).contains(&y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sweat_color() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_sweat(true)
+            .set_sweat_color(100)
+            .set_sweat_radius(0);
+        let img = eyes.draw_eyes(0);
+
+        let (x, y, w, h) = eyes.sweat_drops.0[0].params();
+        assert!(w > 0 && h > 0);
+        assert_eq!(img.get_pixel(x as u32, y as u32)[0], 100);
+    }
+
+    #[test]
+    fn test_shiver() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.anim_shiver(600);
+
+        for t in (0..600).step_by(20) {
+            eyes.draw_eyes(t);
+            assert_eq!(eyes.flicker_amplitudes(), (Some(2), Some(1)));
+        }
+
+        eyes.draw_eyes(600);
+        assert!(!eyes.shiver);
+        assert_eq!(eyes.flicker_amplitudes(), (None, None));
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut plain = RoboEyes::new(128, 64);
+        let mut flipped = RoboEyes::new(128, 64);
+        for eyes in [&mut plain, &mut flipped] {
+            eyes.set_mood(Mood::Skeptical)
+                .set_position(Position::NorthWest);
+            eyes.open();
+        }
+        flipped.set_flip_horizontal(true);
+
+        let mut reference = GrayImage::new(128, 64);
+        let mut img = GrayImage::new(128, 64);
+        for t in 0..20 {
+            reference = plain.draw_eyes(t * 20);
+            img = flipped.draw_eyes(t * 20);
+        }
+        assert_ne!(img, reference);
+        assert_eq!(img, image::imageops::flip_horizontal(&reference));
+
+        // Both flips equal a 180° rotation
+        flipped.set_flip_vertical(true);
+        let img = flipped.draw_eyes(400);
+        let reference = plain.draw_eyes(400);
+        assert_eq!(img, image::imageops::rotate180(&reference));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let mut plain = RoboEyes::new(128, 64);
+        let mut rotated = RoboEyes::new(128, 64);
+        for eyes in [&mut plain, &mut rotated] {
+            eyes.set_position(Position::NorthWest);
+            eyes.open();
+        }
+        rotated.set_rotation(Rotation::Deg90);
+        assert_eq!(rotated.output_size(), (64, 128));
+
+        let mut reference = GrayImage::new(128, 64);
+        let mut img = GrayImage::new(64, 128);
+        for t in 0..20 {
+            reference = plain.draw_eyes(t * 20);
+            img = rotated.draw_eyes(t * 20);
+        }
+        assert_eq!(img.dimensions(), (64, 128));
+
+        // Top-left lit pixel of the left eye, (x, y) -> (h - 1 - y, x)
+        let (x, y) = (
+            plain.eye_l_x as u32 + plain.eye_l.width / 2,
+            plain.eye_l_y as u32,
+        );
+        assert_eq!(reference.get_pixel(x, y)[0], MAINCOLOR);
+        assert_eq!(img.get_pixel(63 - y, x)[0], MAINCOLOR);
+        assert_eq!(img, image::imageops::rotate90(&reference));
+    }
+
+    #[test]
+    fn test_gaze() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.draw_eyes(0);
+        let (x, y) = eyes.gaze();
+        assert!(x.abs() < 0.05 && y.abs() < 0.05);
+
+        eyes.set_position(Position::East);
+        for t in 1..20 {
+            eyes.draw_eyes(t * 20);
+        }
+        let (x, y) = eyes.gaze();
+        assert!((x - 1.0).abs() < 0.05, "gaze x = {x}");
+        assert!(y.abs() < 0.05, "gaze y = {y}");
+
+        eyes.set_position(Position::NorthWest);
+        for t in 20..40 {
+            eyes.draw_eyes(t * 20);
+        }
+        assert_eq!(eyes.gaze(), (-1.0, -1.0));
+    }
+
+    #[test]
+    fn test_blink_complete_callback() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        // Callbacks keep the eyes movable to another thread
+        fn assert_send<T: Send>() {}
+        assert_send::<RoboEyes>();
+
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_autoblinker(true, 1, 1);
+
+        let count = Arc::new(AtomicU32::new(0));
+        let counter = count.clone();
+        eyes.on_blink_complete(Box::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }));
+
+        // Autoblinks at 0ms, 1000ms and 2000ms
+        for t in (0..900).step_by(20) {
+            eyes.draw_eyes(t);
+        }
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+        for t in (900..2900).step_by(20) {
+            eyes.draw_eyes(t);
+        }
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+
+        // Clones don't inherit callbacks
+        let mut fork = eyes.clone();
+        fork.blink();
+        for t in (2900..3400).step_by(20) {
+            fork.draw_eyes(t);
+        }
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_curious_growth_is_smooth() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_curiosity(true).set_curiosity_amount(16);
+        for t in 0..10 {
+            eyes.draw_eyes(t * 20);
+        }
+        assert_eq!(eyes.eye_r_height_offset, 0);
+
+        eyes.set_position(Position::East);
+        let mut offsets = vec![eyes.eye_r_height_offset];
+        for t in 10..30 {
+            eyes.draw_eyes(t * 20);
+            offsets.push(eyes.eye_r_height_offset);
+        }
+
+        assert_eq!(*offsets.last().unwrap(), 16);
+        let max_step = offsets.windows(2).map(|w| w[1] - w[0]).max().unwrap();
+        assert!(max_step <= 8, "offset jumped by {max_step}");
+        let mut distinct = offsets.clone();
+        distinct.dedup();
+        assert!(distinct.len() > 3, "offset steps: {offsets:?}");
+    }
+
+    #[test]
+    fn test_eyebrows() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Angry).set_eyebrows(true);
+        let mut img = GrayImage::new(128, 64);
+        for t in 0..20 {
+            eyes.draw_into(&mut img, t * 20);
+        }
+
+        // Topmost lit pixel of a column, i.e. the eyebrow
+        let top = |x: i32| (0..64).find(|&y| img.get_pixel(x as u32, y)[0] == MAINCOLOR);
+
+        let l_outer = top(eyes.eye_l_x).unwrap();
+        let l_inner = top(eyes.eye_l_x + eyes.eye_l.width as i32 - 1).unwrap();
+        let r_inner = top(eyes.eye_r_x).unwrap();
+        let r_outer = top(eyes.eye_r_x + eyes.eye_r.width as i32 - 1).unwrap();
+
+        // Above the eyes, sloping down towards the centre
+        assert!((l_outer as i32) < eyes.eye_l_y);
+        assert!(l_inner > l_outer);
+        assert!(r_inner > r_outer);
+        assert_eq!(l_inner - l_outer, r_inner - r_outer);
+    }
+
+    #[test]
+    fn test_pixel_aspect() {
+        let render = |ratio: f32| {
+            let mut eyes = RoboEyes::new(128, 64);
+            eyes.open();
+            eyes.set_border_radius(8, 8).set_pixel_aspect(ratio);
+            let mut img = GrayImage::new(128, 64);
+            for t in 0..10 {
+                eyes.draw_into(&mut img, t * 20);
+            }
+            (img, eyes.eye_l_x as u32, eyes.eye_l_y as u32)
+        };
+
+        // Square pixels: left edge is solid 10px below the top
+        let (img, x, y) = render(1.0);
+        assert_eq!(img.get_pixel(x, y + 10)[0], MAINCOLOR);
+        assert_eq!(img.get_pixel(x + 10, y)[0], MAINCOLOR);
+
+        // 2:1 pixels: the corner reaches twice as far down, but not across
+        let (img, x, y) = render(2.0);
+        assert_eq!(img.get_pixel(x, y + 10)[0], BGCOLOR);
+        assert_eq!(img.get_pixel(x, y + 17)[0], MAINCOLOR);
+        assert_eq!(img.get_pixel(x + 10, y)[0], MAINCOLOR);
+    }
+
+    #[test]
+    fn test_idle_bias() {
+        // Mean distance of idle X targets from the centre of the range
+        let spread = |bias: f32| {
+            let mut eyes = RoboEyes::new(128, 64);
+            eyes.set_idle_mode(true, 1, 1, 100, 100).set_idle_bias(bias);
+            let center = eyes.get_constraint_x() as f32 / 2.0;
+            let samples = 2000;
+            let mut total = 0.0;
+            for _ in 0..samples {
+                eyes.idle_timer = 0;
+                eyes.process_idle();
+                total += (eyes.eye_l_x_next as f32 - center).abs();
+            }
+            total / samples as f32
+        };
+
+        let uniform = spread(0.0);
+        let biased = spread(3.0);
+        assert!(biased < uniform * 0.6, "uniform {uniform}, biased {biased}");
+    }
+
+    #[test]
+    fn test_animation_queue() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for t in 0..10 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        eyes.queue_animation(Anim::Blink)
+            .queue_animation(Anim::Laugh);
+        assert_eq!(eyes.queue_len(), 2);
+
+        let mut blink_frame = None;
+        let mut laugh_frame = None;
+        for frame in 10..100 {
+            eyes.draw_eyes(frame * 20);
+            if blink_frame.is_none() && eyes.eye_l_height_next == 1 {
+                blink_frame = Some(frame);
+                assert!(!eyes.laugh);
+            }
+            if laugh_frame.is_none() && eyes.laugh {
+                laugh_frame = Some(frame);
+                assert!(!eyes.blink_in_progress);
+            }
+        }
+
+        let (blink_frame, laugh_frame) = (blink_frame.unwrap(), laugh_frame.unwrap());
+        assert!(blink_frame < laugh_frame);
+        assert_eq!(eyes.queue_len(), 0);
+        assert!(!eyes.laugh, "laugh should have finished");
+
+        eyes.queue_animation(Anim::Wait(1000))
+            .queue_animation(Anim::Confused);
+        eyes.clear_queue();
+        assert_eq!(eyes.queue_len(), 0);
+    }
+
+    #[test]
+    fn test_idle_y_within_screen() {
+        let mut eyes = RoboEyes::new(256, 128);
+        eyes.set_idle_mode(true, 1, 1, 100, 100);
+        let max_y = 128 - eyes.eye_l.height as i32;
+        for _ in 0..500 {
+            eyes.idle_timer = 0;
+            eyes.process_idle();
+            assert!((0..=max_y).contains(&eyes.eye_l_y_next));
+        }
+
+        // Eyes taller than the screen must not panic
+        let mut eyes = RoboEyes::new(64, 16);
+        eyes.set_idle_mode(true, 1, 1, 100, 100);
+        eyes.process_idle();
+        assert_eq!(eyes.eye_l_y_next, 0);
+    }
+
+    #[test]
+    fn test_flicker_stays_on_screen() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_h_flicker(true, 40).set_v_flicker(true, 40);
+
+        let mut xs = Vec::new();
+        for t in 0..50 {
+            eyes.draw_eyes(t * 20);
+            assert!(eyes.eye_l_x >= 0);
+            assert!(eyes.eye_r_x + eyes.eye_r.width as i32 <= 128);
+            assert!(eyes.eye_l_y >= 0);
+            assert!(eyes.eye_l_y + eyes.eye_l_height_current as i32 <= 64);
+            xs.push(eyes.eye_l_x);
+        }
+
+        // Still shaking, not frozen at an edge
+        assert!(xs.windows(2).all(|w| w[0] != w[1]));
+
+        // Cyclops uses the single eye's right edge
+        eyes.set_cyclops(true).set_flicker_margin(4);
+        for t in 50..100 {
+            eyes.draw_eyes(t * 20);
+            assert!(eyes.eye_l_x >= 4);
+            assert!(eyes.eye_l_x + eyes.eye_l.width as i32 <= 124);
+        }
+    }
+
+    #[test]
+    fn test_scanner() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_scanner(true);
+
+        let mut positions = Vec::new();
+        for t in [100, 400, 900, 1500] {
+            let img = eyes.draw_eyes(t);
+            let (x, _) = eyes.scanner_x();
+            assert!(x >= 0 && x + SCANNER_WIDTH as i32 <= 128);
+            assert_eq!(
+                img.get_pixel(x as u32 + SCANNER_WIDTH / 2, 32)[0],
+                MAINCOLOR
+            );
+            positions.push(x);
+        }
+        assert_ne!(positions[0], positions[1]);
+
+        // Bounces back on the return sweep
+        assert!(positions[3] < positions[2]);
+    }
+
+    #[test]
+    fn test_draw_into_argb() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_colors(200, 10);
+
+        let mut buf = vec![0u32; 128 * 64];
+        eyes.draw_into_argb(&mut buf, 0).unwrap();
+        let x = eyes.eye_l_x as usize + eyes.eye_l.width as usize / 2;
+        let y = eyes.eye_l_y as usize + 2;
+        assert_eq!(buf[y * 128 + x], 0xFFC8C8C8);
+        assert_eq!(buf[0], 0xFF0A0A0A);
+
+        let mut short = vec![0u32; 10];
+        assert_eq!(
+            eyes.draw_into_argb(&mut short, 20),
+            Err(BotEyesError::BufferSize {
+                expected: (128, 64),
+                actual: (10, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_tween_factor_frame_rate_independent() {
+        // Time from blink() until the eye is fully open again
+        let blink_time = |interval: u64| {
+            let mut eyes = RoboEyes::new(128, 64);
+            eyes.open();
+            eyes.set_tween_factor(0.5);
+            let mut t = 0;
+            while t < 500 {
+                eyes.draw_eyes(t);
+                t += interval;
+            }
+
+            eyes.blink();
+            let start = t;
+            eyes.draw_eyes(t);
+            while eyes.eye_l_height_current != eyes.eye_l_height_default {
+                t += interval;
+                eyes.draw_eyes(t);
+            }
+            t - start
+        };
+
+        let fast = blink_time(8) as i64;
+        let slow = blink_time(32) as i64;
+        assert!((fast - slow).abs() <= 64, "8ms: {fast}ms, 32ms: {slow}ms");
+    }
+
+    #[test]
+    fn test_three_eyes() {
+        let mut eyes = RoboEyes::new(160, 64);
+        eyes.open();
+        eyes.set_eye_count(3);
+        let mut img = GrayImage::new(160, 64);
+        for t in 0..20 {
+            eyes.draw_into(&mut img, t * 20);
+        }
+
+        // Count separate lit runs along the middle row
+        let y = (eyes.eye_l_y + eyes.eye_l_height_current as i32 / 2) as u32;
+        let mut clusters = 0;
+        let mut lit = false;
+        for x in 0..160 {
+            let on = img.get_pixel(x, y)[0] == MAINCOLOR;
+            if on && !lit {
+                clusters += 1;
+            }
+            lit = on;
+        }
+        assert_eq!(clusters, 3);
+
+        // Whole row stays on screen when moved to the edge
+        eyes.set_position(Position::East);
+        for t in 20..40 {
+            eyes.draw_into(&mut img, t * 20);
+        }
+        assert_eq!(eyes.eye_r_x + eyes.eye_r.width as i32, 160);
+
+        eyes.set_eye_count(1);
+        assert!(eyes.is_cyclops());
+
+        // Middle eyes follow the left eye's scale and get their own features
+        let mut eyes = RoboEyes::new(200, 64);
+        eyes.set_eye_count(3).set_eyebrows(true).open();
+        eyes.set_mood(Mood::Confuse);
+        let mut img = GrayImage::new(200, 64);
+        for t in 0..20 {
+            eyes.draw_into(&mut img, t * 20);
+        }
+        let drawn = eyes.drawn_eyes();
+        assert_eq!(drawn.len(), 3);
+        let (left, middle) = (drawn[0].rect, drawn[1].rect);
+        assert!(left.w > eyes.eye_l.width);
+        assert_eq!((middle.w, middle.h, middle.y), (left.w, left.h, left.y));
+
+        let brow_y = (middle.y - eyes.eye_l_height_default as i32 / 4) as u32;
+        let center_x = (middle.x + middle.w as i32 / 2) as u32;
+        assert_eq!(img.get_pixel(center_x, brow_y)[0], MAINCOLOR);
+    }
+
+    // Golden image regression tests
+    //
+    // Renders are compared pixel-by-pixel against PNGs in `tests/golden/`.
+    // After an intentional rendering change, regenerate the references with
+    //
+    //     BOTEYES_UPDATE_GOLDEN=1 cargo test golden
+    //
+    // and review the changed images before committing them.
+
+    fn assert_matches_golden(img: &GrayImage, name: &str) {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(format!("{name}.png"));
+
+        if std::env::var_os("BOTEYES_UPDATE_GOLDEN").is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            img.save(&path).unwrap();
+            return;
+        }
+
+        let golden = image::open(&path)
+            .unwrap_or_else(|e| panic!("missing golden {}: {e}", path.display()))
+            .to_luma8();
+        assert_eq!(
+            img.dimensions(),
+            golden.dimensions(),
+            "{name}: size differs"
+        );
+
+        let diffs: Vec<(u32, u32)> = img
+            .enumerate_pixels()
+            .filter(|(x, y, p)| golden.get_pixel(*x, *y) != *p)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert!(
+            diffs.is_empty(),
+            "{name}: {} pixels differ, first at {:?}",
+            diffs.len(),
+            &diffs[..diffs.len().min(10)]
+        );
+    }
+
+    /// Render `eyes` up to t=1000ms at 50 FPS
+    fn render_golden(mut eyes: RoboEyes) -> GrayImage {
+        eyes.set_seed(0);
+        eyes.open();
+        let mut img = GrayImage::new(128, 64);
+        for t in (0..=1000).step_by(20) {
+            eyes.draw_into(&mut img, t);
+        }
+        img
+    }
+
+    #[test]
+    fn test_golden_moods() {
+        for (mood, name) in [
+            (Mood::Default, "default"),
+            (Mood::Happy, "happy"),
+            (Mood::Angry, "angry"),
+            (Mood::Sad, "tired"),
+        ] {
+            let mut eyes = RoboEyes::new(128, 64);
+            eyes.set_mood(mood);
+            assert_matches_golden(&render_golden(eyes), name);
+        }
+
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_cyclops(true);
+        assert_matches_golden(&render_golden(eyes), "cyclops");
+    }
+
+    #[test]
+    fn test_draw_into_at() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        let mut reference = GrayImage::new(128, 64);
+        let mut target = GrayImage::from_pixel(256, 128, image::Luma([7]));
+        let mut twin = eyes.clone();
+        for t in 0..10 {
+            eyes.draw_into(&mut reference, t * 20);
+            twin.draw_into_at(&mut target, 128, 64, t * 20);
+        }
+
+        // Bottom-right quadrant holds the frame, the rest is untouched
+        for (x, y, p) in reference.enumerate_pixels() {
+            assert_eq!(target.get_pixel(x + 128, y + 64), p);
+        }
+        assert_eq!(target.get_pixel(0, 0)[0], 7);
+        assert_eq!(target.get_pixel(127, 127)[0], 7);
+        assert_eq!(target.get_pixel(255, 63)[0], 7);
+
+        // Partly off the target is clipped without panicking
+        twin.draw_into_at(&mut target, 200, -30, 220);
+        twin.draw_into_at(&mut target, -500, 500, 240);
+    }
+
+    #[test]
+    fn test_acknowledge() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for t in 0..10 {
+            eyes.draw_eyes(t * 20);
+        }
+        let (center_x, center_y) = (eyes.eye_l_x, eyes.eye_l_y);
+
+        eyes.anim_acknowledge();
+        let mut max_y = center_y;
+        let mut blinked = false;
+        for t in 10..40 {
+            eyes.draw_eyes(t * 20);
+            max_y = max_y.max(eyes.eye_l_y);
+            blinked |= eyes.eye_l_height_next == 1;
+        }
+
+        assert!(max_y > center_y + 5, "gaze should dip south");
+        assert!(blinked);
+        assert!(!eyes.acknowledge);
+        assert_eq!((eyes.eye_l_x, eyes.eye_l_y), (center_x, center_y));
+
+        // A re-trigger mid-nod still returns to centre, and a large gaze
+        // deadzone doesn't swallow the nod
+        eyes.set_gaze_deadzone(100);
+        eyes.anim_acknowledge();
+        eyes.draw_eyes(800);
+        assert!(eyes.eye_l_y_next > center_y);
+        eyes.anim_acknowledge();
+        for t in 41..80 {
+            eyes.draw_eyes(t * 20);
+        }
+        assert_eq!((eyes.eye_l_x, eyes.eye_l_y), (center_x, center_y));
+    }
+
+    #[test]
+    fn test_blink_speed() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_blink_speed(1000, 200);
+        for t in 0..20 {
+            eyes.draw_eyes(t * 20);
+        }
+        assert_eq!(eyes.eye_l_height_current, eyes.eye_l_height_default);
+
+        eyes.blink();
+        let start = 400;
+        let mut closed_at = None;
+        let mut t = start;
+        while t < start + 2000 {
+            eyes.draw_eyes(t);
+            if t == start + 500 {
+                // Halfway through a slow close
+                let half = eyes.eye_l_height_default / 2;
+                assert!(eyes.eye_l_height_current.abs_diff(half) <= 2);
+            }
+            if closed_at.is_none() && eyes.eye_l_height_current <= 1 {
+                closed_at = Some(t - start);
+            }
+            t += 20;
+        }
+
+        let closed_at = closed_at.unwrap();
+        assert!(
+            (900..=1020).contains(&closed_at),
+            "closed after {closed_at}ms"
+        );
+        assert_eq!(eyes.eye_l_height_current, eyes.eye_l_height_default);
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Happy);
+        let mut svg = String::new();
+        for t in 0..10 {
+            svg = eyes.render_svg(t * 20);
+        }
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("viewBox=\"0 0 128 64\""));
+        // Two eyes in the foreground color, cut by background eyelids
+        let eye_rects = svg
+            .lines()
+            .filter(|l| l.starts_with("<rect") && l.contains("fill=\"#ffffff\""))
+            .count();
+        assert_eq!(eye_rects, 2);
+        assert!(svg.contains("fill=\"#000000\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_geometry_validation() {
+        let mut eyes = RoboEyes::new(128, 64);
+        assert_eq!(eyes.set_size(0, 30), Err(BotEyesError::ZeroSize));
+        assert_eq!(
+            eyes.set_size(60, 30),
+            Err(BotEyesError::EyeTooLarge {
+                width: 60,
+                height: 30
+            })
+        );
+        assert_eq!(
+            eyes.set_space_between(200),
+            Err(BotEyesError::SpacingTooLarge { space: 200 })
+        );
+        assert!(eyes.set_space_between(60).is_err());
+
+        // Rejected values leave the geometry untouched
+        assert_eq!(eyes.eye_l.width, 36);
+        assert_eq!(eyes.space_between, 10);
+
+        assert!(eyes.set_size(40, 40).is_ok());
+        assert!(eyes.set_space_between(20).is_ok());
+        eyes.set_size_unchecked(0, 0);
+        assert_eq!(eyes.eye_l.width, 0);
+
+        // Zero variation means fixed intervals
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_autoblinker(true, 1, 0)
+            .set_idle_mode(true, 1, 0, 50, 50)
+            .open();
+        let mut blinked = false;
+        for frame in 0..200 {
+            eyes.draw_eyes(frame * 16);
+            blinked |= eyes.eye_l_height_next == eyes.closed_height;
+        }
+        assert!(blinked);
+    }
+
+    #[test]
+    fn test_thinking_spinner() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_thinking(true).set_thinking_speed(800);
+
+        let mut dots = Vec::new();
+        for t in [0, 200, 400, 600] {
+            let img = eyes.draw_eyes(t);
+            let (x, y) = eyes.thinking_dot();
+            assert!((1..127).contains(&x) && (1..63).contains(&y));
+            assert_eq!(img.get_pixel(x as u32, y as u32)[0], MAINCOLOR);
+            dots.push((x, y));
+        }
+
+        // A quarter turn apart: every sample is a different position
+        for (i, a) in dots.iter().enumerate() {
+            assert!(dots[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn test_inverted() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Happy).set_inverted(true);
+        let mut img = GrayImage::new(128, 64);
+        for t in 0..20 {
+            eyes.draw_into(&mut img, t * 20);
+        }
+
+        // Light background, dark eye body
+        assert_eq!(img.get_pixel(0, 0)[0], MAINCOLOR);
+        let x = (eyes.eye_l_x + eyes.eye_l.width as i32 / 2) as u32;
+        let y = eyes.eye_l_y as u32 + 2;
+        assert_eq!(img.get_pixel(x, y)[0], BGCOLOR);
+
+        // The happy eyelid cuts out with the (now light) background
+        let bottom = (eyes.eye_l_y + eyes.eye_l_height_current as i32 - 2) as u32;
+        assert_eq!(img.get_pixel(x, bottom)[0], MAINCOLOR);
+    }
+
+    #[test]
+    fn test_palette_antialias() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_antialias(true);
+        for t in 0..10 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        let palette = vec![Rgb([0, 0, 0]), Rgb([255, 0, 0]), Rgb([255, 255, 255])];
+        let mut twin = eyes.clone();
+        let gray = twin.draw_eyes(200);
+        let mut rgb = RgbImage::new(128, 64);
+        eyes.set_palette(palette.clone());
+        eyes.draw_into_rgb(&mut rgb, 200);
+
+        // Anti-aliased edge pixels around half coverage use the middle entry
+        let edge = gray
+            .enumerate_pixels()
+            .find(|(_, _, p)| (96..=160).contains(&p[0]))
+            .map(|(x, y, _)| (x, y))
+            .expect("anti-aliasing should produce partial coverage");
+        assert_eq!(*rgb.get_pixel(edge.0, edge.1), palette[1]);
+
+        // Solid areas use the ends of the palette
+        assert_eq!(*rgb.get_pixel(0, 0), palette[0]);
+        let x = (eyes.eye_l_x + eyes.eye_l.width as i32 / 2) as u32;
+        let y = (eyes.eye_l_y + 10) as u32;
+        assert_eq!(*rgb.get_pixel(x, y), palette[2]);
+    }
+
+    #[test]
+    fn test_cyclops_toggle_restores_right_eye() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for t in 0..10 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        eyes.set_cyclops(true);
+        for t in 10..20 {
+            eyes.draw_eyes(t * 20);
+        }
+        eyes.set_cyclops(false);
+        let mut img = eyes.draw_eyes(400);
+        for t in 21..30 {
+            img = eyes.draw_eyes(t * 20);
+        }
+
+        assert_eq!(eyes.eye_r.width, 36);
+        assert_eq!(eyes.space_between, 10);
+        assert_eq!(eyes.eye_r_height_current, eyes.eye_r_height_default);
+
+        // Right eye is drawn at full width once it has grown back
+        let y = (eyes.eye_r_y + 10) as u32;
+        let lit = (0..128)
+            .filter(|&x| x as i32 >= eyes.eye_r_x && img.get_pixel(x, y)[0] == MAINCOLOR)
+            .count();
+        assert_eq!(lit, 36);
+    }
+
+    #[test]
+    fn test_try_draw_into_size_mismatch() {
+        let mut eyes = RoboEyes::new(128, 64);
+        let mut small = GrayImage::new(64, 32);
+        assert_eq!(
+            eyes.try_draw_into(&mut small, 0),
+            Err(BotEyesError::BufferSize {
+                expected: (128, 64),
+                actual: (64, 32)
+            })
+        );
+        assert!(small.pixels().all(|p| p[0] == 0));
+
+        let mut full = GrayImage::new(128, 64);
+        assert!(eyes.try_draw_into(&mut full, 0).is_ok());
+    }
+
+    #[test]
+    fn test_gaze_speed() {
+        // Frames until the left eye settles at the East position
+        let frames_to_east = |gaze: Option<f32>| {
+            let mut eyes = RoboEyes::new(128, 64);
+            eyes.open();
+            if let Some(factor) = gaze {
+                eyes.set_gaze_speed(factor);
+            }
+            eyes.draw_eyes(0);
+            eyes.set_position(Position::East);
+            let target = eyes.eye_l_x_next;
+            let mut frames = 0;
+            while eyes.eye_l_x != target {
+                frames += 1;
+                eyes.draw_eyes(frames * 16);
+            }
+            frames
+        };
+
+        let default = frames_to_east(None);
+        let slow = frames_to_east(Some(0.1));
+        assert!(slow > default, "slow: {slow}, default: {default}");
+    }
+
+    #[test]
+    fn test_eye_rects_match_rendered_pixels() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_position(Position::NorthEast);
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..20 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+
+        let (left, right) = eyes.eye_rects();
+        let right = right.expect("right eye outside cyclops mode");
+        let split = (left.x + left.w as i32 + right.x) / 2;
+
+        // Bounding box of the lit pixels left of the gap
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (i32::MAX, i32::MAX, 0, 0);
+        for (x, y, p) in img.enumerate_pixels() {
+            let (x, y) = (x as i32, y as i32);
+            if p[0] == MAINCOLOR && x < split {
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+            }
+        }
+        assert_eq!(
+            left,
+            Rect {
+                x: min_x,
+                y: min_y,
+                w: (max_x - min_x + 1) as u32,
+                h: (max_y - min_y + 1) as u32,
+            }
+        );
+
+        eyes.set_cyclops(true);
+        for frame in 20..30 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(eyes.eye_rects().1, None);
+    }
+
+    #[test]
+    fn test_saccades_stay_near_target() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_seed(7);
+        eyes.open();
+        eyes.draw_eyes(0);
+        let (base_x, base_y) = (eyes.eye_l_x_next, eyes.eye_l_y_next);
+        for frame in 1..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+
+        eyes.set_saccades(true, 2);
+        let mut xs = std::collections::HashSet::new();
+        for frame in 20..400 {
+            eyes.draw_eyes(frame * 16);
+            assert!((eyes.eye_l_x - base_x).abs() <= 2, "x {}", eyes.eye_l_x);
+            assert!((eyes.eye_l_y - base_y).abs() <= 2, "y {}", eyes.eye_l_y);
+            xs.insert((eyes.eye_l_x, eyes.eye_l_y));
+        }
+        assert!(xs.len() > 1, "eyes never moved");
+
+        eyes.set_saccades(false, 2);
+        assert_eq!((eyes.eye_l_x_next, eyes.eye_l_y_next), (base_x, base_y));
+    }
+
+    #[test]
+    fn test_frames_iterator() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.draw_eyes(0);
+        eyes.set_position(Position::East);
+
+        let frames: Vec<GrayImage> = eyes.frames(5, 16).collect();
+        assert_eq!(frames.len(), 5);
+        for pair in frames.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+        assert_eq!(eyes.current_time, 80);
+    }
+
+    #[test]
+    fn test_mood_transition_effect() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood_transition_effect(true);
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let full = eyes.eye_l_height_current;
+
+        // Same mood: no blink
+        eyes.set_mood(Mood::Default);
+        eyes.draw_eyes(20 * 16);
+        assert_eq!(eyes.eye_l_height_current, full);
+
+        eyes.set_mood(Mood::Angry);
+        let mut min = full;
+        for frame in 21..30 {
+            eyes.draw_eyes(frame * 16);
+            min = min.min(eyes.eye_l_height_current);
+        }
+        assert!(min < full / 2, "no dip: min {min}, full {full}");
+
+        for frame in 30..60 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(eyes.eye_l_height_current, full);
+    }
+
+    #[test]
+    fn test_cyclops_transition_is_gradual() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let full = eyes.eye_rects().1.unwrap();
+
+        // Collapsing: the right eye narrows over several frames
+        eyes.set_cyclops(true);
+        let mut widths = Vec::new();
+        for frame in 20..40 {
+            eyes.draw_eyes(frame * 16);
+            widths.push(eyes.eye_rects().1.map_or(0, |r| r.w));
+        }
+        assert!(widths[0] > 0 && widths[0] < full.w, "{widths:?}");
+        assert!(widths.windows(2).all(|w| w[1] <= w[0]), "{widths:?}");
+        assert_eq!(*widths.last().unwrap(), 0);
+
+        // And grows back when disabled
+        eyes.set_cyclops(false);
+        eyes.draw_eyes(40 * 16);
+        let w = eyes.eye_rects().1.unwrap().w;
+        assert!(w > 0 && w < full.w, "{w}");
+        for frame in 41..60 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(eyes.eye_rects().1, Some(full));
+
+        // The collapsing eye keeps its mood eyelid until it's gone
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Angry);
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        eyes.set_cyclops(true);
+        let img = eyes.draw_eyes(20 * 16);
+        let right = eyes.eye_rects().1.unwrap();
+        let y = (right.y + eyes.eyelids_angry_height as i32 / 2) as u32;
+        let (inner, outer) = (right.x + 1, right.x + right.w as i32 - 2);
+        assert_eq!(img.get_pixel(inner as u32, y)[0], BGCOLOR);
+        assert_eq!(img.get_pixel(outer as u32, y)[0], MAINCOLOR);
+    }
+
+    #[test]
+    fn test_draw_packed_threshold() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_colors(100, 0);
+        let mut buf = vec![0u8; 16 * 64];
+        for frame in 0..10 {
+            eyes.draw_packed(&mut buf, frame * 16).unwrap();
+        }
+        let bit = |buf: &[u8], x: i32, y: i32| {
+            buf[y as usize * 16 + x as usize / 8] & (0x80 >> (x % 8)) != 0
+        };
+        let (left, _) = eyes.eye_rects();
+        let (cx, cy) = (left.x + left.w as i32 / 2, left.y + left.h as i32 / 2);
+
+        // A dim foreground is below the default threshold
+        assert!(buf.iter().all(|&b| b == 0));
+
+        eyes.set_binary_threshold(50);
+        eyes.draw_packed(&mut buf, 10 * 16).unwrap();
+        assert!(bit(&buf, cx, cy));
+        assert!(!bit(&buf, 0, 0));
+
+        assert_eq!(
+            eyes.draw_packed(&mut [0u8; 8], 0),
+            Err(BotEyesError::BufferSize {
+                expected: (128, 64),
+                actual: (8, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_set_position_instant() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.draw_eyes(0);
+
+        eyes.set_position_instant(Position::East);
+        let (x, y) = (eyes.eye_l_x_next, eyes.eye_l_y_next);
+        eyes.draw_eyes(16);
+        assert_eq!(eyes.eye_l_x, x);
+        assert_eq!(eyes.eye_r_x, eyes.eye_r_x_next);
+        assert_eq!(eyes.eye_r_x + eyes.eye_r.width as i32, 128);
+        assert!((eyes.eye_l_y - y).abs() <= 1);
+
+        eyes.center_instant();
+        eyes.draw_eyes(32);
+        assert_eq!(eyes.eye_l_x, eyes.get_constraint_x() / 2);
+    }
+
+    #[test]
+    fn test_corner_radii_top_only() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_corner_radii(Side::Left, 10, 10, 0, 0);
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..10 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+
+        let (left, right) = eyes.eye_rects();
+        let (x0, y0) = (left.x as u32, left.y as u32);
+        let (x1, y1) = (x0 + left.w - 1, y0 + left.h - 1);
+        assert_eq!(img.get_pixel(x0, y0)[0], BGCOLOR);
+        assert_eq!(img.get_pixel(x1, y0)[0], BGCOLOR);
+        assert_eq!(img.get_pixel(x0, y1)[0], MAINCOLOR);
+        assert_eq!(img.get_pixel(x1, y1)[0], MAINCOLOR);
+
+        // The right eye keeps its uniform radius
+        let right = right.unwrap();
+        let (rx, ry) = (right.x as u32, (right.y + right.h as i32 - 1) as u32);
+        assert_eq!(img.get_pixel(rx, ry)[0], BGCOLOR);
+
+        eyes.set_border_radius(8, 8);
+        assert_eq!(eyes.eye_l.corners(), CornerRadii::uniform(8));
+    }
+
+    #[test]
+    fn test_anim_startle() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let y_h = |eyes: &RoboEyes| {
+            let (left, _) = eyes.eye_rects();
+            (left.y, left.h)
+        };
+        let (rest_y, rest_h) = y_h(&eyes);
+
+        eyes.anim_startle();
+        let (mut min_y, mut max_h) = (rest_y, rest_h);
+        for frame in 20..34 {
+            eyes.draw_eyes(frame * 16);
+            let (y, h) = y_h(&eyes);
+            min_y = min_y.min(y);
+            max_h = max_h.max(h);
+        }
+        assert!(min_y < rest_y - 4, "min y {min_y}, rest {rest_y}");
+        assert!(max_h > rest_h, "max h {max_h}, rest {rest_h}");
+
+        for frame in 34..60 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(y_h(&eyes), (rest_y, rest_h));
+
+        // Re-triggering mid-jump still settles back to the rest position
+        eyes.anim_startle();
+        eyes.draw_eyes(60 * 16);
+        eyes.draw_eyes(62 * 16);
+        eyes.anim_startle();
+        for frame in 63..100 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(y_h(&eyes), (rest_y, rest_h));
+    }
+
+    #[test]
+    fn test_double_blink() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_autoblinker(true, 3, 1)
+            .set_double_blink_chance(1.0);
+
+        // Times at which the eyes start closing
+        let mut closes = Vec::new();
+        let mut closing = false;
+        for t in (0..2000).step_by(16) {
+            eyes.draw_eyes(t);
+            let now_closing = eyes.eye_l_height_next == 1;
+            if now_closing && !closing {
+                closes.push(t);
+            }
+            closing = now_closing;
+        }
+        assert_eq!(closes.len(), 2, "{closes:?}");
+        assert!(closes[1] - closes[0] <= 300, "{closes:?}");
+    }
+
+    #[test]
+    fn test_idle_return_passes_center() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_seed(3);
+        eyes.open();
+        eyes.set_idle_mode(true, 1, 1, 100, 100)
+            .set_idle_return(true);
+        let center = (eyes.get_constraint_x() / 2, eyes.get_constraint_y() / 2);
+
+        // Distinct gaze targets in order, and whether the eyes reached centre
+        let mut targets = vec![];
+        let mut reached_center = false;
+        for t in (0..2000).step_by(16) {
+            eyes.draw_eyes(t);
+            let target = (eyes.eye_l_x_next, eyes.eye_l_y_next);
+            if targets.last() != Some(&target) {
+                targets.push(target);
+            }
+            if t > 16 && t < 1000 && (eyes.eye_l_x, eyes.eye_l_y) == center {
+                reached_center = true;
+            }
+        }
+
+        assert!(targets.len() >= 3, "{targets:?}");
+        assert_ne!(targets[0], center);
+        assert_eq!(targets[1], center);
+        assert_ne!(targets[2], center);
+        assert!(reached_center);
+    }
+
+    #[test]
+    fn test_squint() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..10 {
+            eyes.draw_eyes(frame * 16);
+        }
+
+        eyes.squint(0.5);
+        for frame in 10..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let half = eyes.eye_l_height_default / 2;
+        assert!(eyes.eye_rects().0.h.abs_diff(half) <= 1);
+
+        // Blinks reopen to the squint
+        eyes.blink();
+        for frame in 20..40 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert!(eyes.eye_rects().0.h.abs_diff(half) <= 1);
+
+        eyes.squint(1.0);
+        for frame in 40..50 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(eyes.eye_l_height_current, eyes.eye_l_height_default);
+    }
+
+    #[test]
+    fn test_is_animating() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert!(!eyes.is_animating(), "{:?}", eyes.active_animations());
+
+        eyes.anim_confused();
+        eyes.draw_eyes(20 * 16);
+        assert!(eyes.is_animating());
+        assert_eq!(eyes.active_animations(), vec![AnimKind::Confused]);
+
+        for frame in 21..80 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert!(!eyes.is_animating(), "{:?}", eyes.active_animations());
+
+        eyes.blink();
+        assert_eq!(eyes.active_animations(), vec![AnimKind::Blink]);
+    }
+
+    #[test]
+    fn test_easing_curves() {
+        // Left eye x after each of the first frames of a move to East
+        let track = |easing: Easing| {
+            let mut eyes = RoboEyes::new(128, 64);
+            eyes.open();
+            eyes.set_easing(Some(easing));
+            for frame in 0..20 {
+                eyes.draw_eyes(frame * 16);
+            }
+            eyes.set_position(Position::East);
+            let xs: Vec<i32> = (20..40)
+                .map(|frame| {
+                    eyes.draw_eyes(frame * 16);
+                    eyes.eye_l_x
+                })
+                .collect();
+            (xs, eyes.eye_l_x_next)
+        };
+
+        let (linear, target) = track(Easing::Linear);
+        let (ease_out, _) = track(Easing::EaseOut);
+        let (ease_in, _) = track(Easing::EaseIn);
+        assert!(ease_out[1] > linear[1], "{ease_out:?} vs {linear:?}");
+        assert!(ease_in[1] < linear[1], "{ease_in:?} vs {linear:?}");
+        for xs in [linear, ease_out, ease_in] {
+            assert_eq!(*xs.last().unwrap(), target);
+        }
+
+        // Curious-mode lift: a new target doesn't restart the curve from a
+        // doubly lifted spot, and the lift stays applied once settled
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_curiosity(true)
+            .set_easing(Some(Easing::Linear))
+            .open();
+        eyes.set_position(Position::NorthWest);
+        for frame in 0..40 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let lift = eyes.eye_l_height_offset as i32 / 2;
+        assert!(lift > 0);
+        eyes.set_position(Position::SouthWest);
+        let ys: Vec<i32> = std::iter::once(eyes.eye_l_y)
+            .chain((40..80).map(|frame| {
+                eyes.draw_eyes(frame * 16);
+                eyes.eye_l_y
+            }))
+            .collect();
+        assert!(ys.windows(2).all(|w| w[1] >= w[0]), "{ys:?}");
+        assert_eq!(*ys.last().unwrap(), eyes.eye_l_y_next - lift);
+
+        // And easing can be switched off again
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_easing(Some(Easing::Linear)).set_easing(None);
+        assert_eq!(eyes.easing, None);
+    }
+
+    #[test]
+    fn test_eye_speed_lazy_close() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+
+        eyes.set_eye_speed(Side::Left, 0.5);
+        eyes.close();
+        let (mut left_open, mut right_open) = (0, 0);
+        for frame in 20..40 {
+            eyes.draw_eyes(frame * 16);
+            left_open += (eyes.eye_l_height_current > 1) as u32;
+            right_open += (eyes.eye_r_height_current > 1) as u32;
+        }
+        assert!(
+            left_open > right_open,
+            "left {left_open}, right {right_open}"
+        );
+        assert_eq!(eyes.eye_l_height_current, 1);
+    }
+
+    #[test]
+    fn test_heartbeat_two_peaks_per_beat() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for t in (0..500).step_by(10) {
+            eyes.draw_eyes(t);
+        }
+        eyes.set_heartbeat(true, 60);
+
+        // Heights over two beats, with repeats collapsed
+        let mut heights: Vec<u32> = vec![];
+        for t in (1000..3000).step_by(10) {
+            eyes.draw_eyes(t);
+            if heights.last() != Some(&eyes.eye_l_height_current) {
+                heights.push(eyes.eye_l_height_current);
+            }
+        }
+        let peaks = heights
+            .windows(3)
+            .filter(|w| w[1] > w[0] && w[1] > w[2])
+            .count();
+        assert_eq!(peaks, 4, "{heights:?}");
+        assert!(heights.iter().max().unwrap() > &eyes.eye_l_height_default);
+    }
+
+    #[test]
+    fn test_shadow() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_shadow(true, 3, 128);
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..10 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+
+        let (left, _) = eyes.eye_rects();
+        let mid_y = (left.y + left.h as i32 / 2) as u32;
+        let right_edge = (left.x + left.w as i32) as u32;
+        let bottom = (left.y + left.h as i32) as u32;
+        let mid_x = (left.x + left.w as i32 / 2) as u32;
+
+        assert_eq!(img.get_pixel(mid_x, mid_y)[0], MAINCOLOR);
+        assert_eq!(img.get_pixel(right_edge + 1, mid_y)[0], 128);
+        assert_eq!(img.get_pixel(mid_x, bottom + 1)[0], 128);
+        assert_eq!(img.get_pixel(left.x as u32, bottom + 1)[0], BGCOLOR);
+
+        // Left out of 1-bpp output
+        let mut buf = vec![0u8; 16 * 64];
+        eyes.set_binary_threshold(100);
+        eyes.draw_packed(&mut buf, 10 * 16).unwrap();
+        let x = right_edge + 1;
+        assert_eq!(
+            buf[mid_y as usize * 16 + x as usize / 8] & (0x80 >> (x % 8)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_blink_pattern() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.draw_eyes(0);
+
+        eyes.blink_pattern(&[(true, 200), (false, 100), (true, 300)]);
+        let mut state = vec![];
+        for t in (10..800).step_by(10) {
+            eyes.draw_eyes(t);
+            state.push((t, eyes.eye_l_open));
+        }
+        let open_at = |time: u64| state.iter().find(|&&(t, _)| t == time).unwrap().1;
+
+        assert!(!open_at(100));
+        assert!(open_at(250));
+        assert!(!open_at(400));
+        assert!(!open_at(600));
+        assert!(open_at(700));
+    }
+
+    #[test]
+    fn test_pupil_dilation() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_pupils(true);
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..20 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+        let (left, _) = eyes.eye_rects();
+        let centre = (
+            (left.x + left.w as i32 / 2) as u32,
+            (left.y + left.h as i32 / 2) as u32,
+        );
+        assert_eq!(img.get_pixel(centre.0, centre.1)[0], BGCOLOR);
+        let calm = eyes.pupil_radius(left);
+
+        eyes.set_mood(Mood::Surprise);
+        let mut radii = vec![];
+        for frame in 20..30 {
+            eyes.draw_eyes(frame * 16);
+            radii.push(eyes.pupil_radius(eyes.eye_rects().0));
+        }
+        assert!(
+            radii[0] > calm && radii[0] < *radii.last().unwrap(),
+            "{calm} {radii:?}"
+        );
+
+        // Never reaches the eye edge, even fully dilated
+        eyes.set_pupil_dilation(1.0);
+        for frame in 30..50 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let (left, _) = eyes.eye_rects();
+        assert!(eyes.pupil_radius(left) < left.w.min(left.h) / 2);
+    }
+
+    #[test]
+    fn test_render_mood_mask() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Angry);
+        let mut mask = eyes.render_mood_mask(0);
+        for frame in 1..30 {
+            mask = eyes.render_mood_mask(frame * 16);
+        }
+        let (left, right) = eyes.eye_rects();
+        let right = right.unwrap();
+
+        // Lids slope down towards the nose: inner top corners are covered,
+        // outer top corners and the bottom of the eyes are not
+        let at = |x: i32, y: i32| mask.get_pixel(x as u32, y as u32)[0];
+        assert_eq!(at(left.x + left.w as i32 - 2, left.y + 1), 255);
+        assert_eq!(at(right.x + 1, right.y + 1), 255);
+        assert_eq!(at(left.x + 1, left.y + left.h as i32 / 2), 0);
+        assert_eq!(
+            at(right.x + right.w as i32 - 2, right.y + right.h as i32 / 2),
+            0
+        );
+        assert_eq!(
+            at(left.x + left.w as i32 / 2, left.y + left.h as i32 - 2),
+            0
+        );
+
+        // Default mood has no overlay at all
+        eyes.set_mood(Mood::Default);
+        for frame in 30..60 {
+            mask = eyes.render_mood_mask(frame * 16);
+        }
+        assert!(mask.pixels().all(|p| p[0] == 0));
+        // The normal output still has its colors
+        assert_eq!(eyes.draw_eyes(60 * 16).get_pixel(0, 0)[0], BGCOLOR);
+    }
+
+    #[test]
+    fn test_closed_thickness() {
+        let lit_rows = |img: &GrayImage, x: u32| {
+            (0..img.height())
+                .filter(|&y| img.get_pixel(x, y)[0] == MAINCOLOR)
+                .count()
+        };
+
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_closed_thickness(3);
+        eyes.open();
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..20 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+        let x = (eyes.eye_l_x + eyes.eye_l.width as i32 / 2) as u32;
+        assert_eq!(lit_rows(&img, x), 36);
+
+        eyes.close();
+        for frame in 20..40 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(lit_rows(&img, x), 3);
+
+        // Blinks reopen from the thicker bar
+        eyes.blink();
+        for frame in 40..60 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(lit_rows(&img, x), 36);
+    }
+
+    #[test]
+    fn test_anim_survives_clock_going_backwards() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.draw_eyes(10_000);
+        eyes.anim_confused();
+        eyes.draw_eyes(10_016);
+        assert!(eyes.confused);
+
+        // Clock reset (e.g. a restarted timer source): still ends ~500ms later
+        for t in (0..=600).step_by(20) {
+            eyes.draw_eyes(t);
+        }
+        assert!(!eyes.confused);
+        assert_eq!(eyes.flicker_amplitudes(), (None, None));
+
+        // Absurd durations are cut off by the watchdog instead of overflowing
+        eyes.anim_laugh_for(u64::MAX);
+        eyes.draw_eyes(1_000);
+        assert!(eyes.laugh);
+        eyes.draw_eyes(1_000 + ANIM_WATCHDOG_MS);
+        assert!(!eyes.laugh);
+    }
+
+    #[test]
+    fn test_express_emotion() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.express(Emotion::Nervous);
+        assert_eq!(eyes.mood, Mood::Default);
+        assert!(eyes.sweat && eyes.saccades && eyes.idle);
+
+        // Switching clears the previous bundle's effects
+        eyes.express(Emotion::Bored);
+        assert_eq!(eyes.mood, Mood::Sad);
+        assert!(!eyes.sweat && !eyes.saccades);
+        assert!(eyes.idle_config.interval > 1);
+
+        eyes.open();
+        for frame in 0..100 {
+            eyes.draw_eyes(frame * 16);
+        }
+    }
+
+    #[test]
+    fn test_idle_instant() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_seed(7);
+        eyes.open();
+        eyes.center_instant();
+        eyes.set_idle_mode(true, 1, 1, 100, 100)
+            .set_idle_instant(true)
+            .set_idle_return(true);
+
+        // Each idle move (and the return to centre) lands in the frame it
+        // is chosen in
+        let mut moves = 0;
+        for frame in 0..400 {
+            let before = (eyes.eye_l_x, eyes.eye_l_y);
+            eyes.draw_eyes(frame * 16);
+            let after = (eyes.eye_l_x, eyes.eye_l_y);
+            assert_eq!(after, (eyes.eye_l_x_next, eyes.eye_l_y_next));
+            assert_eq!(eyes.eye_r_x, eyes.right_eye_x(after.0));
+            moves += (before != after) as u32;
+        }
+        assert!(moves >= 4, "{moves}");
+    }
+
+    #[test]
+    fn test_anim_wake() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.anim_wake();
+        assert!(eyes.active_animations().contains(&AnimKind::Wake));
+
+        let heights: Vec<u32> = (0..120)
+            .map(|frame| {
+                eyes.draw_eyes(frame * 16);
+                eyes.eye_l_height_current
+            })
+            .collect();
+        assert_eq!(heights[0], 1);
+        assert_eq!(*heights.last().unwrap(), 36);
+        assert!(!eyes.wake && eyes.eye_l_open);
+
+        // Rises gradually, with at least one flutter on the way up
+        let peak = heights.iter().position(|&h| h == 36).unwrap();
+        assert!(peak > 40, "{heights:?}");
+        assert!(
+            heights[..peak].windows(2).any(|w| w[1] < w[0]),
+            "{heights:?}"
+        );
+    }
+
+    #[test]
+    fn test_draw_into_checked() {
+        let mut eyes = RoboEyes::new(128, 64);
+        let mut buffer = GrayImage::new(128, 64);
+        eyes.open();
+        assert!(eyes.draw_into_checked(&mut buffer, 0));
+
+        // Opening animation still running
+        assert!(eyes.draw_into_checked(&mut buffer, 16));
+        for frame in 2..30 {
+            eyes.draw_into_checked(&mut buffer, frame * 16);
+        }
+
+        // Settled: nothing to push
+        assert!(!eyes.draw_into_checked(&mut buffer, 30 * 16));
+        assert!(!eyes.draw_into_checked(&mut buffer, 31 * 16));
+
+        eyes.set_position(Position::East);
+        assert!(eyes.draw_into_checked(&mut buffer, 32 * 16));
+    }
+
+    #[test]
+    fn test_spring_easing() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_easing(Some(Easing::Spring(0.2)));
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        eyes.set_position(Position::East);
+        let target = eyes.eye_l_x_next;
+        let start = eyes.eye_l_x;
+
+        let xs: Vec<i32> = (20..40)
+            .map(|frame| {
+                eyes.draw_eyes(frame * 16);
+                eyes.eye_l_x
+            })
+            .collect();
+        let peak = *xs.iter().max().unwrap();
+        assert!(peak > target, "{xs:?}");
+        // About 20% of the distance, give or take a frame
+        assert!(((peak - target) as f32) < 0.25 * (target - start) as f32);
+        assert_eq!(*xs.last().unwrap(), target);
+
+        // Closing with an overshoot never wraps the height around
+        eyes.close();
+        for frame in 40..60 {
+            eyes.draw_eyes(frame * 16);
+            assert!(eyes.eye_l_height_current <= 36);
+        }
+
+        // Even a large overshoot has died out just before the end
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_easing(Some(Easing::Spring(0.8)));
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        eyes.set_position(Position::East);
+        let target = eyes.eye_l_x_next;
+        let xs: Vec<i32> = (0..EASING_MS / 2 + 10)
+            .map(|step| {
+                eyes.draw_eyes(20 * 16 + step * 2);
+                eyes.eye_l_x
+            })
+            .collect();
+        let last_moving = xs.iter().rposition(|&x| x != target).unwrap();
+        assert!((xs[last_moving] - target).abs() <= 1, "{xs:?}");
+        assert_eq!(*xs.last().unwrap(), target);
+    }
+
+    #[test]
+    fn test_eye_gradient() {
+        let (red, blue) = (Rgb([255, 0, 0]), Rgb([0, 0, 255]));
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_eye_gradient(red, blue);
+        let mut img = RgbImage::new(128, 64);
+        for frame in 0..20 {
+            eyes.draw_into_rgb(&mut img, frame * 16);
+        }
+
+        let (left, _) = eyes.eye_rects();
+        let x = (left.x + left.w as i32 / 2) as u32;
+        let top = img.get_pixel(x, left.y as u32);
+        let bottom = img.get_pixel(x, (left.y + left.h as i32 - 1) as u32);
+        assert!(top[0] > top[2], "{top:?}");
+        assert!(bottom[2] > bottom[0], "{bottom:?}");
+
+        // Background stays the palette background
+        assert_eq!(*img.get_pixel(0, 0), Rgb([BGCOLOR; 3]));
+    }
+
+    #[test]
+    fn test_cluster_offset() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let (left, right) = eyes.eye_rects();
+        let right = right.unwrap();
+
+        eyes.set_cluster_offset(-30, 0);
+        for frame in 20..40 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let (shifted_left, shifted_right) = eyes.eye_rects();
+        let shifted_right = shifted_right.unwrap();
+        assert_eq!(shifted_left.x, left.x - 30);
+        assert_eq!(shifted_right.x, right.x - 30);
+        assert_eq!(shifted_left.y, left.y);
+        assert_eq!(eyes.gaze(), (0.0, 0.0));
+
+        // Way past the edge: the right end of the eyes stays visible
+        eyes.set_cluster_offset(-1000, 1000);
+        eyes.center_instant();
+        let (left, _) = eyes.eye_rects();
+        assert!(eyes.eye_r_x + eyes.eye_r.width as i32 > 0);
+        assert!(left.y < 64);
+    }
+
+    #[test]
+    fn test_timer_wraparound() {
+        let start = u64::MAX - 1_000;
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_autoblinker(true, 3, 1);
+        eyes.blink_timer = start;
+        eyes.draw_eyes(start);
+        // Next blink is scheduled past the wrap
+        assert!(eyes.blink_timer < start);
+        for frame in 1..20 {
+            eyes.draw_eyes(start + frame * 16);
+        }
+
+        let mut blinked = false;
+        let mut t = start + 20 * 16;
+        for _ in 0..300 {
+            t = t.wrapping_add(16);
+            eyes.draw_eyes(t);
+            blinked |= eyes.eye_l_height_next == 1;
+        }
+        assert!(t < start, "clock wrapped");
+        assert!(blinked);
+        // Crossing the wrap isn't mistaken for the clock going backwards
+        assert_eq!(eyes.frame_ms, 16);
+    }
+
+    #[test]
+    fn test_laugh_style() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_h_flicker(false, 7);
+        eyes.set_laugh_style(LaughStyle::Horizontal);
+        eyes.anim_laugh();
+        eyes.draw_eyes(0);
+        assert_eq!(eyes.flicker_amplitudes(), (Some(5), None));
+
+        // Changing the style mid-laugh keeps shaking the axis in use
+        eyes.set_laugh_style(LaughStyle::Vertical);
+        eyes.draw_eyes(300);
+        assert_eq!(eyes.flicker_amplitudes(), (Some(5), None));
+        eyes.draw_eyes(600);
+        assert_eq!(eyes.flicker_amplitudes(), (None, None));
+        assert_eq!(eyes.h_flicker_amplitude, 7);
+
+        eyes.set_laugh_style(LaughStyle::Both);
+        eyes.anim_laugh();
+        eyes.draw_eyes(700);
+        assert_eq!(eyes.flicker_amplitudes(), (Some(5), Some(5)));
+    }
+
+    #[test]
+    fn test_anim_peek() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let center = eyes.eye_l_x;
+
+        eyes.anim_peek(Position::West);
+        let img = eyes.draw_eyes(20 * 16);
+        assert!(eyes.eye_r_x + (eyes.eye_r.width as i32) <= 0);
+        assert!(img.pixels().all(|p| p[0] == BGCOLOR));
+        assert!(eyes.is_animating());
+
+        let mut xs = vec![];
+        for frame in 21..60 {
+            eyes.draw_eyes(frame * 16);
+            xs.push(eyes.eye_l_x);
+        }
+        assert!(xs.windows(2).all(|w| w[1] >= w[0]), "{xs:?}");
+        assert_eq!(eyes.eye_l_x, center);
+        assert!(!eyes.peek);
+    }
+
+    #[test]
+    fn test_cyclops_centered() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        eyes.set_cyclops(true);
+        for frame in 20..50 {
+            eyes.draw_eyes(frame * 16);
+        }
+        let (eye, right) = eyes.eye_rects();
+        assert!(right.is_none());
+        assert_eq!(eye.x + eye.w as i32 / 2, 64);
+
+        // Keeping the right eye: square corners of the right eye survive
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_corner_radii(Side::Right, 0, 0, 0, 0);
+        eyes.set_cyclops_eye(Side::Right).set_cyclops(true);
+        eyes.open();
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..30 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+        let (eye, right) = eyes.eye_rects();
+        assert!(right.is_none());
+        assert_eq!(eye.x + eye.w as i32 / 2, 64);
+        assert_eq!(img.get_pixel(eye.x as u32, eye.y as u32)[0], MAINCOLOR);
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_draw_stats() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..20 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+
+        let stats = eyes.stats();
+        let lit = img.pixels().filter(|p| p[0] == MAINCOLOR).count() as u64;
+        assert_eq!(stats.rects_drawn, 2);
+        assert_eq!(stats.triangles_drawn, 0);
+        assert_eq!(stats.pixels_written, lit);
+        assert!(lit > 2 * 30 * 30 && lit <= 2 * 36 * 36, "{lit}");
+
+        eyes.set_mood(Mood::Angry);
+        eyes.draw_eyes(20 * 16);
+        assert_eq!(eyes.stats().triangles_drawn, 2);
+        assert!(eyes.stats().pixels_written > lit);
+    }
+
+    #[test]
+    fn test_split_color() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_split_color(16, 180, 90);
+        eyes.open();
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..20 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+
+        let rows = |range: std::ops::Range<u32>| {
+            range
+                .flat_map(|y| (0..128).map(move |x| (x, y)))
+                .map(|(x, y)| img.get_pixel(x, y)[0])
+                .filter(|&p| p != BGCOLOR)
+                .collect::<Vec<_>>()
+        };
+        let above = rows(0..16);
+        let below = rows(16..64);
+        assert!(!above.is_empty() && !below.is_empty());
+        assert!(above.iter().all(|&p| p == 180));
+        assert!(below.iter().all(|&p| p == 90));
+    }
+
+    #[test]
+    fn test_mood_affects_radius() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.set_mood(Mood::Happy);
+        eyes.draw_eyes(0);
+        assert_eq!(eyes.eye_l.border_radius, 8, "off by default");
+
+        eyes.set_mood_affects_radius(true);
+        let mut radii = Vec::new();
+        for frame in 1..10 {
+            eyes.draw_eyes(frame * 16);
+            radii.push(eyes.eye_l.border_radius);
+        }
+        assert!(radii.windows(2).all(|w| w[0] <= w[1]), "{radii:?}");
+        assert!(radii[0] > 8 && radii[0] < 16, "{radii:?}");
+        assert_eq!(*radii.last().unwrap(), 16);
+        assert_eq!(eyes.settings().border_radius_left, 8);
+
+        eyes.set_mood(Mood::Angry);
+        for frame in 10..20 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(eyes.eye_l.border_radius, 4);
+    }
+
+    #[test]
+    fn test_gaze_deadzone() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_gaze_deadzone(4).set_position(Position::Center);
+        let target = (eyes.eye_l_x_next, eyes.eye_l_y_next);
+
+        let max_x = eyes.get_constraint_x() as f32 / 2.0;
+        for (x, y) in [(0.02, 0.0), (-0.05, 0.02), (0.0, -0.1), (0.04, 0.04)] {
+            eyes.look_at(x, y);
+            assert_eq!((eyes.eye_l_x_next, eyes.eye_l_y_next), target);
+        }
 
-        self.eye_r_x = (self.eye_r_x as f32 + self.eye_r_x_next as f32) as i32 / 2;
-        self.eye_r_y = (self.eye_r_y as f32 + self.eye_r_y_next as f32) as i32 / 2;
-    }
+        eyes.look_at(10.0 / max_x, 0.0);
+        assert_eq!(eyes.eye_l_x_next, target.0 + 10);
+        eyes.set_position(Position::East);
+        assert_eq!(eyes.eye_l_x_next, eyes.get_constraint_x());
 
-    fn get_constraint_x(&self) -> i32 {
-        (self.screen_width as i32)
-            - self.eye_l.width as i32
-            - self.space_between as i32
-            - self.eye_r.width as i32
-    }
-    fn get_constraint_y(&self) -> i32 {
-        (self.screen_height as i32) - self.eye_l.height as i32
-    }
-    fn process_autoblinker(&mut self) {
-        if self.autoblinker && self.current_time >= self.blink_timer {
-            self.blink();
-            let mut rng = rand::thread_rng();
-            self.blink_timer = self.current_time
-                + self.blink_config.interval * 1000
-                + rng.gen_range(0..self.blink_config.variation) * 1000;
+        // The gaze settles where look_at pointed
+        eyes.look_at(-1.0, 1.0);
+        for frame in 0..40 {
+            eyes.draw_eyes(frame * 16);
         }
+        assert_eq!(eyes.gaze(), (-1.0, 1.0));
     }
 
-    fn process_laugh(&mut self) {
-        if self.laugh {
-            if self.laugh_toggle {
-                self.v_flicker = true;
-                self.v_flicker_amplitude = 5;
-                self.laugh_timer = self.current_time;
-                self.laugh_toggle = false;
-            } else if self.current_time >= self.laugh_timer + self.laugh_duration {
-                self.v_flicker = false;
-                self.v_flicker_amplitude = 0;
-                self.laugh_toggle = true;
-                self.laugh = false;
+    #[test]
+    fn test_mood_intensity() {
+        let lit_after = |intensity: Option<f32>| {
+            let mut eyes = RoboEyes::new(128, 64);
+            if let Some(intensity) = intensity {
+                eyes.set_mood_intensity(intensity);
             }
-        }
+            eyes.set_mood(Mood::Angry).open();
+            let mut img = eyes.draw_eyes(0);
+            for frame in 1..30 {
+                img = eyes.draw_eyes(frame * 16);
+            }
+            let lit = img.pixels().filter(|p| p[0] == MAINCOLOR).count();
+            (eyes.eyelids_angry_height, lit)
+        };
+
+        let (default_lid, default_lit) = lit_after(None);
+        let (furious_lid, furious_lit) = lit_after(Some(1.0));
+        let (frown_lid, _) = lit_after(Some(0.3));
+        assert_eq!(default_lid, 18);
+        assert_eq!(furious_lid, 36);
+        assert_eq!(frown_lid, 10);
+        // The slanted lids now reach the bottom edge of the eyes
+        assert!(
+            furious_lit * 4 < default_lit * 3,
+            "{furious_lit} vs {default_lit}"
+        );
     }
 
-    fn process_confused(&mut self) {
-        if self.confused {
-            if self.confused_toggle {
-                self.h_flicker = true;
-                self.h_flicker_amplitude = 20;
-                self.confused_timer = self.current_time;
-                self.confused_toggle = false;
-            } else if self.current_time >= self.confused_timer + self.confused_duration {
-                self.h_flicker = false;
-                self.h_flicker_amplitude = 0;
-                self.confused_toggle = true;
-                self.confused = false;
-            }
+    #[test]
+    fn test_debug_state() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_mood(Mood::Happy)
+            .set_sweat(true)
+            .set_curiosity(true);
+        eyes.open();
+        for frame in 0..30 {
+            eyes.draw_eyes(frame * 16);
         }
-    }
 
-    fn process_idle(&mut self) {
-        if self.idle && self.current_time >= self.idle_timer {
-            let mut rng = rand::thread_rng();
+        let state = eyes.debug_state();
+        assert_eq!(state[0..2], [eyes.eye_l_x, eyes.eye_l_y]);
+        assert_eq!(state[2] - state[0], 36 + 10);
+        assert_eq!(state[4..7], [36, 36, 10]);
+        assert_eq!(state[7], 2);
+        assert_eq!(state[8], 0);
+        assert_eq!(state[9], 0b11 | 1 << 3 | 1 << 4);
 
-            // Calculate range based on percentage (centered)
-            let max_x = self.get_constraint_x();
-            let max_y = self.get_constraint_y();
-            let x_range_pct = self.idle_config.x_range as f32 / 100.0;
-            let y_range_pct = self.idle_config.y_range as f32 / 100.0;
+        eyes.set_cyclops(true).pause();
+        eyes.draw_eyes(30 * 16);
+        assert_eq!(eyes.debug_state()[9] & (1 << 2 | 1 << 11), 1 << 2 | 1 << 11);
+    }
 
-            let x_range = (max_x as f32 * x_range_pct) as i32;
-            let y_range = (max_y as f32 * y_range_pct) as i32;
+    #[test]
+    fn test_iris() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_iris(true, 4, 12, 128).set_position(Position::East);
+        eyes.open();
+        let mut img = eyes.draw_eyes(0);
+        for frame in 1..40 {
+            img = eyes.draw_eyes(frame * 16);
+        }
 
-            // Center the range within available space
-            let x_offset = (max_x - x_range) / 2;
-            let y_offset = (max_y - y_range) / 2;
+        let (left, _) = eyes.eye_rects();
+        let pixel = |x: i32, y: i32| img.get_pixel(x as u32, y as u32)[0];
+        // Pupil at the centre, looking East: shifted right of the eye centre
+        let cy = left.y + left.h as i32 / 2;
+        let pupil_x = (left.x..left.x + left.w as i32)
+            .filter(|&x| pixel(x, cy) == BGCOLOR)
+            .collect::<Vec<_>>();
+        assert!(!pupil_x.is_empty());
+        let cx = (pupil_x[0] + pupil_x[pupil_x.len() - 1]) / 2;
+        assert!(cx > left.x + left.w as i32 / 2);
 
-            self.eye_l_x_next = x_offset + rng.gen_range(0..=x_range);
-            self.eye_l_y_next = y_offset + rng.gen_range(0..=y_range);
-            self.idle_timer = self.current_time
-                + self.idle_config.interval * 1000
-                + rng.gen_range(0..self.idle_config.variation) * 1000;
+        // Ring of iris pixels all around the pupil, eye color outside it
+        for (dx, dy) in [(8, 0), (-8, 0), (0, 8), (0, -8), (6, 6), (-6, -6)] {
+            assert_eq!(pixel(cx + dx, cy + dy), 128, "({dx}, {dy})");
         }
+        assert_eq!(pixel(left.x + 2, cy), MAINCOLOR);
+        assert!((left.x..left.x + left.w as i32).all(|x| pixel(x, left.y + 1) != 128));
     }
 
-    fn apply_flicker(&mut self) {
-        if self.h_flicker {
-            if self.h_flicker_alternate {
-                self.eye_l_x += self.h_flicker_amplitude as i32;
-                self.eye_r_x += self.h_flicker_amplitude as i32;
-            } else {
-                self.eye_l_x -= self.h_flicker_amplitude as i32;
-                self.eye_r_x -= self.h_flicker_amplitude as i32;
+    #[test]
+    fn test_follow_path() {
+        let mut eyes = RoboEyes::new(128, 64);
+        let (max_x, max_y) = (eyes.get_constraint_x(), eyes.get_constraint_y());
+        let waypoints = [(0, 0, 400), (max_x, 0, 400), (max_x / 2, max_y, 400)];
+        eyes.open();
+        eyes.follow_path(&waypoints);
+
+        let mut positions = Vec::new();
+        for frame in 0..=100 {
+            let t = frame * 16;
+            eyes.draw_eyes(t);
+            if t % 400 == 384 {
+                positions.push((eyes.eye_l_x, eyes.eye_l_y));
             }
-            self.h_flicker_alternate = !self.h_flicker_alternate;
         }
+        // Each waypoint is reached within its dwell, and the last one holds
+        let visited: Vec<_> = waypoints.iter().map(|&(x, y, _)| (x, y)).collect();
+        assert_eq!(positions[..3], visited[..]);
+        assert_eq!(positions[3], visited[2]);
 
-        if self.v_flicker {
-            if self.v_flicker_alternate {
-                self.eye_l_y += self.v_flicker_amplitude as i32;
-                self.eye_r_y += self.v_flicker_amplitude as i32;
-            } else {
-                self.eye_l_y -= self.v_flicker_amplitude as i32;
-                self.eye_r_y -= self.v_flicker_amplitude as i32;
+        eyes.set_path_loop(true);
+        eyes.follow_path(&waypoints);
+        for frame in 101..=200 {
+            let t = frame * 16;
+            eyes.draw_eyes(t);
+            if t == 1616 + 1200 + 384 {
+                assert_eq!((eyes.eye_l_x, eyes.eye_l_y), visited[0]);
             }
-            self.v_flicker_alternate = !self.v_flicker_alternate;
         }
     }
 
-    fn update_mood_transitions(&mut self) {
-        match self.mood {
-            Mood::Angry => {
-                self.eyelids_angry_height_next = self.eye_l_height_default / 2;
-                self.eyelids_tired_height_next = 0;
-                self.eyelids_happy_bottom_offset_next = 0;
-                self.eye_l_scale_next = 1.0;
-                self.eye_r_scale_next = 1.0;
-            }
-            Mood::Happy => {
-                self.eyelids_happy_bottom_offset_next = self.eye_l_height_default / 2;
-                self.eyelids_tired_height_next = 0;
-                self.eyelids_angry_height_next = 0;
-                self.eye_l_scale_next = 1.0;
-                self.eye_r_scale_next = 1.0;
-            }
-            Mood::Confuse => {
-                self.eyelids_tired_height_next = 0;
-                self.eyelids_angry_height_next = 0;
-                self.eyelids_happy_bottom_offset_next = 0;
-                self.eye_l_scale_next = 1.3;
-                self.eye_r_scale_next = 0.7;
-            }
-            Mood::Sad => {
-                // Eyes half-closed and looking down
-                self.eyelids_tired_height_next = self.eye_l_height_default / 2;
-                self.eyelids_angry_height_next = 0;
-                self.eyelids_happy_bottom_offset_next = 0;
-                self.eye_l_scale_next = 1.0;
-                self.eye_r_scale_next = 1.0;
-                // Move eyes downward
-                let max_y = self.get_constraint_y();
-                self.eye_l_y_next = max_y;
-                self.eye_r_y_next = max_y;
-            }
-            Mood::Surprise => {
-                // Eyes wide open (enlarged)
-                self.eyelids_tired_height_next = 0;
-                self.eyelids_angry_height_next = 0;
-                self.eyelids_happy_bottom_offset_next = 0;
-                self.eye_l_scale_next = 1.3;
-                self.eye_r_scale_next = 1.3;
-            }
-            Mood::Loading => {
-                // Eyes normal, animation handled separately
-                self.eyelids_tired_height_next = 0;
-                self.eyelids_angry_height_next = 0;
-                self.eyelids_happy_bottom_offset_next = 0;
-                self.eye_l_scale_next = 1.0;
-                self.eye_r_scale_next = 1.0;
-            }
-            Mood::Default => {
-                self.eyelids_tired_height_next = 0;
-                self.eyelids_angry_height_next = 0;
-                self.eyelids_happy_bottom_offset_next = 0;
-                self.eye_l_scale_next = 1.0;
-                self.eye_r_scale_next = 1.0;
-            }
+    #[test]
+    fn test_anim_wince() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        for frame in 0..20 {
+            eyes.draw_eyes(frame * 16);
         }
 
-        // Tween eyelid values
-        self.eyelids_tired_height =
-            (self.eyelids_tired_height as f32 + self.eyelids_tired_height_next as f32) as u32 / 2;
-        self.eyelids_angry_height =
-            (self.eyelids_angry_height as f32 + self.eyelids_angry_height_next as f32) as u32 / 2;
-        self.eyelids_happy_bottom_offset = (self.eyelids_happy_bottom_offset as f32
-            + self.eyelids_happy_bottom_offset_next as f32)
-            as u32
-            / 2;
+        eyes.anim_wince(400);
+        let mut img = eyes.draw_eyes(20 * 16);
+        for frame in 21..40 {
+            img = eyes.draw_eyes(frame * 16);
+        }
+        assert!(eyes.active_animations().contains(&AnimKind::Wince));
+        let (left, right) = eyes.eye_rects();
+        assert!(left.h <= 36 / 4, "{}", left.h);
+        let right = right.unwrap();
+        let corner = |img: &GrayImage, x: i32, rect: Rect| {
+            img.get_pixel(x as u32, (rect.y + rect.h as i32 / 2) as u32)[0]
+        };
+        assert_eq!(corner(&img, left.x - 2, left), MAINCOLOR);
+        let cy = (left.y + left.h as i32 / 2) as u32;
+        assert_eq!(img.get_pixel(left.x as u32 - 8, cy - 3)[0], MAINCOLOR);
+        assert_eq!(img.get_pixel(left.x as u32 - 8, cy + 3)[0], MAINCOLOR);
+        assert_eq!(corner(&img, right.x + right.w as i32 + 1, right), MAINCOLOR);
 
-        // Tween eye scales for Confuse mood
-        self.eye_l_scale = (self.eye_l_scale + self.eye_l_scale_next) / 2.0;
-        self.eye_r_scale = (self.eye_r_scale + self.eye_r_scale_next) / 2.0;
-    }
-
-    fn draw_eyelids(&mut self, img: &mut GrayImage) {
-        // Sad/Sleepy eyelids
-        if self.mood == Mood::Sad {
-            if !self.cyclops {
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_l_x,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32,
-                    self.eye_l_y - 1,
-                    self.eye_l_x,
-                    self.eye_l_y + self.eyelids_tired_height as i32 - 1,
-                    BGCOLOR,
-                );
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_r_x,
-                    self.eye_r_y - 1,
-                    self.eye_r_x + self.eye_r.width as i32,
-                    self.eye_r_y - 1,
-                    self.eye_r_x + self.eye_r.width as i32,
-                    self.eye_r_y + self.eyelids_tired_height as i32 - 1,
-                    BGCOLOR,
-                );
-            } else {
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_l_x,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32 / 2,
-                    self.eye_l_y - 1,
-                    self.eye_l_x,
-                    self.eye_l_y + self.eyelids_tired_height as i32 - 1,
-                    BGCOLOR,
-                );
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_l_x + self.eye_l.width as i32 / 2,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32,
-                    self.eye_l_y + self.eyelids_tired_height as i32 - 1,
-                    BGCOLOR,
-                );
-            }
+        for frame in 40..80 {
+            img = eyes.draw_eyes(frame * 16);
         }
+        assert!(!eyes.wince);
+        let (left, _) = eyes.eye_rects();
+        assert_eq!(left.h, 36);
+        assert_eq!(corner(&img, left.x - 2, left), BGCOLOR);
 
-        // Angry eyelids
-        if self.mood == Mood::Angry {
-            if !self.cyclops {
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_l_x,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32,
-                    self.eye_l_y + self.eyelids_angry_height as i32 - 1,
-                    BGCOLOR,
-                );
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_r_x,
-                    self.eye_r_y - 1,
-                    self.eye_r_x + self.eye_r.width as i32,
-                    self.eye_r_y - 1,
-                    self.eye_r_x,
-                    self.eye_r_y + self.eyelids_angry_height as i32 - 1,
-                    BGCOLOR,
-                );
-            } else {
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_l_x,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32 / 2,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32 / 2,
-                    self.eye_l_y + self.eyelids_angry_height as i32 - 1,
-                    BGCOLOR,
-                );
-                draw_triangle(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_l_x + self.eye_l.width as i32 / 2,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32,
-                    self.eye_l_y - 1,
-                    self.eye_l_x + self.eye_l.width as i32 / 2,
-                    self.eye_l_y + self.eyelids_angry_height as i32 - 1,
-                    BGCOLOR,
-                );
-            }
+        // Closed during the wince: stays closed afterwards
+        eyes.anim_wince(200);
+        for frame in 80..85 {
+            eyes.draw_eyes(frame * 16);
+        }
+        eyes.close();
+        for frame in 85..120 {
+            eyes.draw_eyes(frame * 16);
         }
+        assert!(!eyes.wince);
+        assert_eq!(eyes.eye_l_height_current, eyes.closed_height);
+        assert_eq!(eyes.eye_r_height_current, eyes.closed_height);
 
-        // Happy eyelids
-        if self.mood == Mood::Happy {
-            let left_happy_y = self.eye_l_y + self.eye_l_height_current as i32
-                - self.eyelids_happy_bottom_offset as i32
-                + 1;
-            draw_rounded_rect(
-                img,
-                self.screen_width,
-                self.screen_height,
-                self.eye_l_x - 1,
-                left_happy_y,
-                self.eye_l.width + 2,
-                self.eye_l_height_current,
-                self.eye_l.border_radius,
-                BGCOLOR,
-            );
+        // Closed before it: squeezes, then shuts again
+        eyes.anim_wince(200);
+        for frame in 120..130 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert!(eyes.eye_l_height_current > eyes.closed_height);
+        for frame in 130..160 {
+            eyes.draw_eyes(frame * 16);
+        }
+        assert_eq!(eyes.eye_l_height_current, eyes.closed_height);
 
-            if !self.cyclops {
-                let right_happy_y = self.eye_r_y + self.eye_r_height_current as i32
-                    - self.eyelids_happy_bottom_offset as i32
-                    + 1;
-                draw_rounded_rect(
-                    img,
-                    self.screen_width,
-                    self.screen_height,
-                    self.eye_r_x - 1,
-                    right_happy_y,
-                    self.eye_r.width + 2,
-                    self.eye_r_height_current,
-                    self.eye_r.border_radius,
-                    BGCOLOR,
-                );
-            }
+        // A single eye gets wrinkles on both sides
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_cyclops(true).open();
+        eyes.anim_wince(400);
+        for frame in 0..20 {
+            img = eyes.draw_eyes(frame * 16);
         }
+        let (eye, _) = eyes.eye_rects();
+        assert_eq!(corner(&img, eye.x - 2, eye), MAINCOLOR);
+        assert_eq!(corner(&img, eye.x + eye.w as i32 + 1, eye), MAINCOLOR);
     }
 
-    fn draw_sweat(&mut self, img: &mut GrayImage) {
-        let resets = self.sweat_drops.update();
+    #[test]
+    fn test_gaze_margin() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_gaze_margin(10).set_position(Position::NorthWest);
+        assert_eq!((eyes.eye_l_x_next, eyes.eye_l_y_next), (10, 10));
 
-        for (i, drop) in self.sweat_drops.0.iter_mut().enumerate() {
-            if resets.contains(&i) {
-                let pos = match i {
-                    0 => SweatPosition::Left,
-                    1 => SweatPosition::Center,
-                    _ => SweatPosition::Right,
-                };
-                drop.reset(self.screen_width, pos);
-            }
+        let (max_x, max_y) = (eyes.get_constraint_x(), eyes.get_constraint_y());
+        eyes.set_position(Position::SouthEast);
+        assert_eq!(
+            (eyes.eye_l_x_next, eyes.eye_l_y_next),
+            (max_x - 10, max_y - 10)
+        );
+        eyes.look_at(-1.0, 0.0);
+        assert_eq!(eyes.eye_l_x_next, 10);
 
-            let (x, y, w, h) = drop.params();
-            draw_rounded_rect(
-                img,
-                self.screen_width,
-                self.screen_height,
-                x,
-                y,
-                w,
-                h,
-                3,
-                MAINCOLOR,
-            );
+        eyes.set_idle_mode(true, 1, 1, 100, 100);
+        for frame in 0..300 {
+            eyes.draw_eyes(frame * 16);
+            assert!((10..=max_x - 10).contains(&eyes.eye_l_x_next));
+            assert!((10..=max_y - 10).contains(&eyes.eye_l_y_next));
         }
     }
 
-    fn draw_loading(&mut self, img: &mut GrayImage) {
-        // Blue flickering effect during loading
-        let cycle = (self.current_time / 200) % 4;
-        let color = match cycle {
-            0 => 255,
-            1 => 200,
-            2 => 100,
-            _ => 50,
-        };
+    #[test]
+    fn test_position_fraction() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_position(Position::Center);
+        let center = (eyes.eye_l_x_next, eyes.eye_l_y_next);
+        eyes.set_position(Position::East);
+        let east = eyes.eye_l_x_next;
 
-        // Draw loading indicator in center of each eye
-        let center_x_l = self.eye_l_x + self.eye_l.width as i32 / 2;
-        let center_y_l = self.eye_l_y + self.eye_l_height_current as i32 / 2;
-        let indicator_size = 4i32;
+        eyes.set_position_fraction(0.5, 0.0);
+        let halfway = (center.0 + east) as f32 / 2.0;
+        assert_eq!(eyes.eye_l_x_next, halfway.round() as i32);
+        assert_eq!(eyes.eye_l_y_next, center.1);
 
-        // Left eye loading
-        draw_rounded_rect(
-            img,
-            self.screen_width,
-            self.screen_height,
-            center_x_l - indicator_size / 2,
-            center_y_l - indicator_size / 2,
-            indicator_size as u32,
-            indicator_size as u32,
-            2,
-            color,
+        eyes.set_position_fraction(-1.0, -1.0);
+        assert_eq!((eyes.eye_l_x_next, eyes.eye_l_y_next), (0, 0));
+    }
+
+    #[test]
+    fn test_status_text() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_status_text("ok").open();
+        let img = eyes.draw_eyes(0);
+
+        // "OK" is 7px wide, centred, with its top row 6px above the bottom
+        let lit = |x: u32, y: u32| img.get_pixel(x, y)[0] == MAINCOLOR;
+        let (x, y) = (61, 58);
+        let rows: Vec<String> = (0..5)
+            .map(|row| {
+                (0..7)
+                    .map(|col| if lit(x + col, y + row) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [".#..#.#", "#.#.#.#", "#.#.##.", "#.#.#.#", ".#..#.#"]
         );
+        assert!((0..128).all(|x| !lit(x, 63) && !lit(x, 57)));
 
-        if !self.cyclops {
-            let center_x_r = self.eye_r_x + self.eye_r.width as i32 / 2;
-            let center_y_r = self.eye_r_y + self.eye_r_height_current as i32 / 2;
-            draw_rounded_rect(
-                img,
-                self.screen_width,
-                self.screen_height,
-                center_x_r - indicator_size / 2,
-                center_y_r - indicator_size / 2,
-                indicator_size as u32,
-                indicator_size as u32,
-                2,
-                color,
+        // Too wide for the screen: scrolls instead of clipping in place
+        eyes.set_status_text(&"HELLO ".repeat(8));
+        let first = eyes.draw_eyes(1000);
+        let later = eyes.draw_eyes(1000 + 10 * STATUS_SCROLL_MS);
+        assert_ne!(first, later);
+
+        // Eyes looking down stop above the status row
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_status_text("ok").open();
+        eyes.set_position(Position::South);
+        let img = (0..60).map(|i| eyes.draw_eyes(i * 16)).last().unwrap();
+        assert!((0..128).all(|x| img.get_pixel(x, 57)[0] != MAINCOLOR));
+
+        // Drawn as text, not as one shape per pixel
+        let svg = eyes.render_svg(60 * 16);
+        assert_eq!(svg.matches("<rect").count(), 2);
+        assert_eq!(svg.matches("<path").count(), 2);
+        #[cfg(feature = "stats")]
+        assert_eq!(eyes.stats().rects_drawn, 2);
+    }
+
+    #[test]
+    fn test_centering_rounds() {
+        // 129 - (36 + 10 + 36) leaves 47px: 24 on the left, 23 on the right
+        let constraints = ScreenConstraints::new(129, 65);
+        assert_eq!(constraints.center_x(36, 10, 36), 24);
+        assert_eq!(constraints.center_y(36), 15);
+
+        let mut eyes = RoboEyes::new(129, 65);
+        eyes.open();
+        assert_eq!(eyes.eye_l_x, 24);
+        eyes.set_position(Position::NorthWest);
+        eyes.set_position(Position::Center);
+        for frame in 1..30 {
+            eyes.draw_eyes(frame * 16);
+        }
+        for (left, right) in [
+            (eyes.eye_l_x, 129 - (eyes.eye_r_x + 36)),
+            (eyes.eye_l_y, 65 - (eyes.eye_l_y + 36)),
+        ] {
+            assert!((left - right).abs() <= 1, "{left} vs {right}");
+            assert!(left >= right, "{left} vs {right}");
+        }
+    }
+
+    #[test]
+    fn test_start_open() {
+        let config = RoboEyesConfig::default().with_start_open(true);
+        let mut eyes = RoboEyes::new_with_config(128, 64, config);
+        let first = eyes.draw_eyes(0);
+
+        let (left, right) = eyes.eye_rects();
+        assert_eq!((left.h, right.unwrap().h), (36, 36));
+        assert_eq!(right.unwrap().x, left.x + 36 + 10);
+        assert_eq!(first, eyes.draw_eyes(500), "nothing left to animate");
+
+        let mut shut = RoboEyes::new(128, 64);
+        shut.draw_eyes(0);
+        assert!(shut.eye_rects().0.h < 36);
+    }
+
+    #[test]
+    fn test_happy_style_curved() {
+        let lowest_lit = |style: HappyStyle| {
+            let mut eyes = RoboEyes::new(128, 64);
+            eyes.set_happy_style(style).set_mood(Mood::Happy).open();
+            let img = eyes.frames(30, 16).last().unwrap();
+            let (left, _) = eyes.eye_rects();
+            let lowest = |x: i32| {
+                (0..64u32)
+                    .rev()
+                    .find(|&y| img.get_pixel(x as u32, y)[0] == MAINCOLOR)
+                    .unwrap() as i32
+            };
+            // Lowest lit row near the outer edge and in the middle
+            (lowest(left.x + 4), lowest(left.x + left.w as i32 / 2))
+        };
+
+        let (flat_edge, flat_middle) = lowest_lit(HappyStyle::Flat);
+        assert!((flat_edge - flat_middle).abs() <= 1);
+
+        // Curved: the edges hang lower than the middle, an upward crescent
+        let (edge, middle) = lowest_lit(HappyStyle::Curved);
+        assert!(edge - middle >= 4, "edge {edge}, middle {middle}");
+        assert!((middle - flat_middle).abs() <= 1);
+
+        // Big anti-aliased eyes pass large eyelid radii to the corner test
+        let mut eyes = RoboEyes::new(256, 128);
+        eyes.set_size(72, 72).unwrap();
+        eyes.set_antialias(true)
+            .set_happy_style(HappyStyle::Curved)
+            .set_mood(Mood::Happy)
+            .open();
+        let img = eyes.frames(30, 16).last().unwrap();
+        assert!(img.pixels().any(|p| p[0] > 0));
+    }
+
+    #[test]
+    fn test_draw_into_sink() {
+        let mut a = RoboEyes::new(128, 64);
+        let mut b = RoboEyes::new(128, 64);
+        a.set_seed(7).set_mood(Mood::Angry).open();
+        b.set_seed(7).set_mood(Mood::Angry).open();
+
+        // The same frames through a gray and an RGB sink light the same pixels
+        let mut gray = GrayImage::new(128, 64);
+        let mut rgb = image::RgbImage::new(128, 64);
+        for t in (0..600).step_by(50) {
+            a.draw_into_sink(&mut gray, t);
+            b.draw_into_sink(&mut rgb, t);
+            let from_gray: Vec<bool> = gray.pixels().map(|p| p[0] == 255).collect();
+            let from_rgb: Vec<bool> = rgb.pixels().map(|p| p.0 == [255; 3]).collect();
+            assert_eq!(from_gray, from_rgb, "sinks disagree at {t}ms");
+        }
+        assert!(gray.pixels().any(|p| p[0] == 255));
+
+        // Raw bytes are cleared and match the packed bits of the same frame
+        let mut bytes = vec![0x55; 128 * 64];
+        let mut packed = vec![0; 16 * 64];
+        a.draw_into_sink(&mut RawBuffer::new(&mut bytes, 128), 650);
+        b.draw_packed(&mut packed, 650).unwrap();
+        assert!(bytes.iter().all(|&v| v == 0 || v == 255));
+        for (i, &v) in bytes.iter().enumerate() {
+            assert_eq!(
+                packed[i / 8] & (0x80 >> (i % 8)) != 0,
+                v == 255,
+                "pixel {i}"
             );
         }
+
+        // Threshold 0 lights every pixel, up to the end of each row
+        a.set_binary_threshold(0);
+        b.set_binary_threshold(0);
+        a.draw_into_sink(&mut RawBuffer::new(&mut bytes, 128), 700);
+        b.draw_packed(&mut packed, 700).unwrap();
+        assert!(bytes.iter().all(|&v| v == 255));
+        assert!(packed.iter().all(|&v| v == 0xFF));
+
+        // Rasterizing into the sink matches thresholding a gray frame, with
+        // and without an effect that needs the gray frame
+        let (mut a, mut c) = (RoboEyes::new(128, 64), RoboEyes::new(128, 64));
+        a.set_seed(7).set_mood(Mood::Angry).open();
+        c.set_seed(7).set_mood(Mood::Angry).open();
+        let lit = |img: &GrayImage| img.pixels().map(|p| p[0] >= 128).collect::<Vec<_>>();
+        for (flip, start) in [(false, 0), (true, 600)] {
+            a.set_flip_horizontal(flip);
+            c.set_flip_horizontal(flip);
+            for t in (start..start + 600).step_by(50) {
+                a.draw_into_sink(&mut gray, t);
+                assert_eq!(lit(&gray), lit(&c.draw_eyes(t)), "flip {flip} at {t}ms");
+            }
+        }
     }
-}
 
-use image::GrayImage;
+    #[test]
+    fn test_sprite_sheet_after_pause() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.draw_eyes(1000);
+        eyes.pause();
+        eyes.draw_eyes(3000);
+        eyes.resume();
+        eyes.draw_eyes(3020);
+        let before = eyes.current_time;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // Continues from the caller's clock, so animation time keeps moving forward
+        eyes.render_sprite_sheet(4, 20);
+        assert_eq!(eyes.last_external_time, 3100);
+        assert_eq!(eyes.current_time, before + 80);
+    }
 
     #[test]
-    fn test_new() {
-        let eyes = RoboEyes::new(128, 64);
-        assert_eq!(eyes.screen_width, 128);
-        assert_eq!(eyes.screen_height, 64);
+    fn test_frames_after_pause() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
+        eyes.draw_eyes(1000);
+        eyes.pause();
+        eyes.draw_eyes(3000);
+        eyes.resume();
+        eyes.draw_eyes(3020);
+
+        // Each frame moves animation time forward by frame_ms
+        for _ in 0..5 {
+            let before = eyes.current_time;
+            assert_eq!(eyes.frames(1, 20).count(), 1);
+            assert_eq!(eyes.current_time, before + 20);
+        }
     }
 
     #[test]
-    fn test_set_mood() {
+    fn test_timer_32bit_wrap() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
         let mut eyes = RoboEyes::new(128, 64);
-        eyes.set_mood(Mood::Happy);
-        assert_eq!(eyes.mood, Mood::Happy);
+        eyes.open();
+        eyes.set_autoblinker(true, 1, 1);
+        eyes.set_idle_mode(true, 1, 1, 100, 100);
+        let moves = Arc::new(AtomicU32::new(0));
+        let counter = moves.clone();
+        eyes.on_idle_move(Box::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }));
+
+        // A millisecond counter from a 32-bit timer, wrapping to 0
+        let start = (1u64 << 32) - 8_000;
+        let mut blinks = 0;
+        for frame in 0..1_500u64 {
+            let t = (start + frame * 16) % (1 << 32);
+            eyes.draw_eyes(t);
+            if t < 16 {
+                blinks = 0;
+                moves.store(0, Ordering::Relaxed);
+            }
+            blinks += (eyes.eye_l_height_next == 1) as u32;
+        }
+
+        // 16s after the wrap: blinking and idling carried on
+        assert!(blinks > 0);
+        assert!(moves.load(Ordering::Relaxed) > 2);
     }
 
     #[test]
-    fn test_set_position() {
+    fn test_render_sprite_sheet() {
         let mut eyes = RoboEyes::new(128, 64);
-        eyes.set_position(Position::NorthEast);
+        eyes.open();
+        let sheet = eyes.render_sprite_sheet(8, 20);
+        assert_eq!(sheet.width(), 8 * 128);
+        assert_eq!(sheet.height(), 64);
+
+        // Last frame matches a direct render at the same timestamp
+        let mut twin = RoboEyes::new(128, 64);
+        twin.open();
+        let mut frame = GrayImage::new(128, 64);
+        for i in 1..=8 {
+            twin.draw_into(&mut frame, i * 20);
+        }
+        let last = image::imageops::crop_imm(&sheet, 7 * 128, 0, 128, 64).to_image();
+        assert_eq!(last, frame);
     }
 
     #[test]
-    fn test_draw_eyes() {
+    #[should_panic(expected = "sprite sheet wider than u32::MAX pixels")]
+    fn test_render_sprite_sheet_too_wide() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.render_sprite_sheet(u32::MAX / 64, 20);
+    }
+
+    #[test]
+    fn test_render_ascii() {
         let mut eyes = RoboEyes::new(128, 64);
-        eyes.set_mood(Mood::Default);
         eyes.open();
-        let img = eyes.draw_eyes(1000);
-        assert_eq!(img.width(), 128);
-        assert_eq!(img.height(), 64);
+        for t in 0..10 {
+            eyes.draw_eyes(t * 20);
+        }
+
+        eyes.set_ascii_cell_size(4, 8);
+        let art = eyes.render_ascii(200);
+        assert!(art.contains('#'));
+        assert_eq!(art.matches('\n').count(), 64 / 8);
+        assert!(art.lines().all(|line| line.chars().count() == 128 / 4));
     }
 
+    #[cfg(feature = "gif")]
     #[test]
-    fn test_blink() {
+    fn test_render_gif() {
+        // Per-process name so concurrent test runs don't share the file
+        let name = format!("boteyes_test_blink_{}.gif", std::process::id());
+        let path = std::env::temp_dir().join(name);
         let mut eyes = RoboEyes::new(128, 64);
+        eyes.open();
         eyes.blink();
+        eyes.render_gif(10, 50, &path).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"GIF89a"));
+        let decoded = image::open(&path).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (128, 64));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_settings_json_roundtrip() {
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_mood(Mood::Angry);
+        eyes.set_size(30, 28).unwrap();
+        eyes.set_border_radius(6, 4);
+        eyes.set_space_between(14).unwrap();
+        eyes.set_curiosity(true);
+        eyes.set_idle_mode(false, 3, 2, 60, 40);
+        eyes.set_h_flicker(true, 3);
+        eyes.open();
+
+        let json = serde_json::to_string(&eyes.settings()).unwrap();
+        let restored: RoboEyesSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, eyes.settings());
+
+        let mut copy = RoboEyes::new(128, 64);
+        copy.apply_settings(&restored);
+        copy.open();
+        assert_eq!(copy.settings(), eyes.settings());
+
+        let mut original = RoboEyes::new(128, 64);
+        original.apply_settings(&eyes.settings());
+        original.open();
+        assert_eq!(original.draw_eyes(1000), copy.draw_eyes(1000));
     }
 }
@@ -0,0 +1,179 @@
+//! Runtime module - Interactive preview window
+//!
+//! Requires the `demo-runtime` feature. Opens a `minifb` window, animates
+//! the eyes in real time and maps keys to moods and modes:
+//!
+//! ```no_run
+//! use boteyes::{RoboEyes, runtime};
+//!
+//! let mut eyes = RoboEyes::new(128, 64);
+//! eyes.open();
+//! runtime::run_window(&mut eyes, 128, 64, 4)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::error::Error;
+use std::time::Instant;
+
+use image::GrayImage;
+use minifb::{Key, KeyRepeat, Scale, Window, WindowOptions};
+
+use crate::{BotEyesError, Mood, RoboEyes, gray_to_argb};
+
+/// Title listing the [default keymap](handle_key)
+const TITLE: &str = "BotEyes - 1-8=Mood, C=Cyclops, S=Sweat, SPACE=Blink, ESC=Quit";
+
+/// Show the eyes in a window until it's closed or Escape is pressed
+///
+/// Keys are handled by [`handle_key()`]. Animation time is the real
+/// time since the window opened.
+///
+/// # Arguments
+///
+/// * `eyes` - Eyes to animate; they keep their state afterwards
+/// * `width`, `height` - Frame size, which must equal [`RoboEyes::output_size()`]
+/// * `scale` - Window pixels per frame pixel, rounded down to a power of two (1-32)
+///
+/// # Errors
+///
+/// Returns [`BotEyesError::BufferSize`] if the size doesn't match the
+/// eyes' output, or the `minifb` error if the window can't be opened or
+/// updated.
+pub fn run_window(
+    eyes: &mut RoboEyes,
+    width: usize,
+    height: usize,
+    scale: usize,
+) -> Result<(), Box<dyn Error>> {
+    let (out_w, out_h) = eyes.output_size();
+    if (width, height) != (out_w as usize, out_h as usize) {
+        return Err(BotEyesError::BufferSize {
+            expected: (out_w, out_h),
+            actual: (width as u32, height as u32),
+        }
+        .into());
+    }
+
+    let mut window = Window::new(
+        TITLE,
+        width,
+        height,
+        WindowOptions {
+            scale: window_scale(scale),
+            ..WindowOptions::default()
+        },
+    )?;
+    window.set_target_fps(60);
+
+    let mut frame = GrayImage::new(out_w, out_h);
+    let mut buffer = vec![0; width * height];
+    let start = Instant::now();
+    while window.is_open() && !window.is_key_down(Key::Escape) {
+        for key in window.get_keys_pressed(KeyRepeat::No) {
+            handle_key(eyes, key);
+        }
+
+        eyes.draw_into(&mut frame, start.elapsed().as_millis() as u64);
+        to_argb(&frame, &mut buffer);
+        window.update_with_buffer(&buffer, width, height)?;
+    }
+
+    Ok(())
+}
+
+/// Apply the default keymap, returning whether `key` is mapped
+///
+/// | Key | Action |
+/// |-----|--------|
+/// | 1-8 | Default, Sad, Angry, Happy, Confuse, Surprise, Loading, Skeptical |
+/// | C | Toggle cyclops mode |
+/// | S | Toggle sweat |
+/// | Space | Blink |
+pub fn handle_key(eyes: &mut RoboEyes, key: Key) -> bool {
+    let mood = match key {
+        Key::Key1 => Mood::Default,
+        Key::Key2 => Mood::Sad,
+        Key::Key3 => Mood::Angry,
+        Key::Key4 => Mood::Happy,
+        Key::Key5 => Mood::Confuse,
+        Key::Key6 => Mood::Surprise,
+        Key::Key7 => Mood::Loading,
+        Key::Key8 => Mood::Skeptical,
+        Key::C => {
+            eyes.set_cyclops(!eyes.is_cyclops());
+            return true;
+        }
+        Key::S => {
+            eyes.set_sweat(!eyes.has_sweat());
+            return true;
+        }
+        Key::Space => {
+            eyes.blink();
+            return true;
+        }
+        _ => return false,
+    };
+    eyes.set_mood(mood);
+    true
+}
+
+/// Convert a grayscale frame to opaque `0xAARRGGBB` words for the window
+///
+/// # Panics
+///
+/// Panics if `buf` doesn't hold exactly one word per pixel.
+pub fn to_argb(frame: &GrayImage, buf: &mut [u32]) {
+    assert_eq!(
+        buf.len(),
+        frame.width() as usize * frame.height() as usize,
+        "buffer length must equal width * height"
+    );
+    for (out, pixel) in buf.iter_mut().zip(frame.pixels()) {
+        *out = gray_to_argb(pixel[0]);
+    }
+}
+
+/// Largest `minifb` scale not above `scale`
+fn window_scale(scale: usize) -> Scale {
+    match scale {
+        0..=1 => Scale::X1,
+        2..=3 => Scale::X2,
+        4..=7 => Scale::X4,
+        8..=15 => Scale::X8,
+        16..=31 => Scale::X16,
+        _ => Scale::X32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_argb() {
+        let frame = GrayImage::from_raw(3, 1, vec![0x00, 0x44, 0xFF]).unwrap();
+        let mut buf = [0; 3];
+        to_argb(&frame, &mut buf);
+        assert_eq!(buf, [0xFF00_0000, 0xFF44_4444, 0xFFFF_FFFF]);
+
+        // A rendered frame keeps its fg/bg levels in every channel
+        let mut eyes = RoboEyes::new(128, 64);
+        eyes.set_colors(0xC0, 0x10).open();
+        let frame = eyes.frames(20, 16).last().unwrap();
+        let mut buf = vec![0; 128 * 64];
+        to_argb(&frame, &mut buf);
+        assert!(buf.contains(&0xFFC0_C0C0));
+        assert!(buf.iter().all(|&w| w == 0xFFC0_C0C0 || w == 0xFF10_1010));
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut eyes = RoboEyes::new(128, 64);
+        assert!(handle_key(&mut eyes, Key::Key3));
+        assert_eq!(eyes.settings().mood, Mood::Angry);
+        assert!(handle_key(&mut eyes, Key::C));
+        assert!(eyes.is_cyclops());
+        assert!(!handle_key(&mut eyes, Key::Q));
+        assert!(matches!(window_scale(3), Scale::X2));
+    }
+}
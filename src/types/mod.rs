@@ -11,6 +11,7 @@
 /// - `Sad`: Eyes half-closed and looking down (sleepy + sad)
 /// - `Surprise`: Eyes wide open (enlarged)
 /// - `Loading`: Rotating or blue light flickering
+/// - `Skeptical`: One eyelid lowered, the other eye fully open (raised eyebrow)
 ///
 /// Trigger scenarios:
 /// - Happy: Heard owner's name, received praise
@@ -18,7 +19,9 @@
 /// - Angry: Pushed, repeated command not understood
 /// - Surprise: Found new object, loud noise
 /// - Loading: 0.5B model reasoning
+/// - Skeptical: Doubtful answer, unconvincing request
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mood {
     Default,
     Angry,
@@ -27,6 +30,24 @@ pub enum Mood {
     Sad,
     Surprise,
     Loading,
+    Skeptical,
+}
+
+/// High-level feelings, each a bundle of mood and ambient animations
+///
+/// Used with `RoboEyes::express()`:
+///
+/// - `Excited`: Happy mood, quick curious glances
+/// - `Content`: Happy mood, calm slow looking around
+/// - `Bored`: Sad (sleepy) mood, slow and narrow idle movement
+/// - `Nervous`: Default mood, sweat and small saccades with restless glances
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Emotion {
+    Excited,
+    Content,
+    Bored,
+    Nervous,
 }
 
 /// Predefined eye positions (gaze directions)
@@ -42,6 +63,7 @@ pub enum Mood {
 ///     SW  S  SE
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Position {
     North,     // Top center
     NorthEast, // Top right corner
@@ -54,10 +76,146 @@ pub enum Position {
     Center,    // Middle center
 }
 
+/// Output rotation (clockwise)
+///
+/// Used for displays mounted sideways or upside-down. For `Deg90` and
+/// `Deg270` the output image has width and height swapped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// Direction the laugh animation bounces in
+///
+/// - `Vertical`: Up and down (default)
+/// - `Horizontal`: Side to side giggle
+/// - `Both`: Diagonal wobble
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LaughStyle {
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+/// Shape of the Happy mood's bottom eyelid
+///
+/// - `Flat`: A rounded rectangle cuts a straight bottom edge (default)
+/// - `Curved`: A wide ellipse cuts an arch, leaving upturned smile-eyes
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HappyStyle {
+    Flat,
+    Curved,
+}
+
+/// Easing curve for position and height transitions
+///
+/// - `Linear`: Constant speed
+/// - `EaseIn`: Starts slow, speeds up
+/// - `EaseOut`: Starts fast, slows down
+/// - `EaseInOut`: Slow at both ends
+/// - `Spring(overshoot)`: Shoots past the target and settles back like a
+///   damped spring; `overshoot` is the peak overshoot as a fraction of the
+///   distance (e.g. `0.2`), clamped to 0.001-1.0
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Spring(f32),
+}
+
+impl Easing {
+    /// Eased progress for linear progress `t` (0.0-1.0)
+    ///
+    /// Ends at exactly 1.0; only `Spring` goes outside 0.0-1.0 on the way.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(2) / 2.0
+                }
+            }
+            Self::Spring(overshoot) => {
+                if t >= 1.0 {
+                    return 1.0;
+                }
+                // 1.5 damped oscillations under a (1 - t) envelope, so the
+                // swing dies out continuously at t = 1. Damping is chosen so
+                // the first peak (near t = 1/3) exceeds 1 by `overshoot`.
+                const CYCLES: f32 = 3.0 * std::f32::consts::PI;
+                let damping = -(1.5 * overshoot.clamp(0.001, 1.0)).ln() * 3.0;
+                1.0 - (-damping * t).exp() * (1.0 - t) * (CYCLES * t).cos()
+            }
+        }
+    }
+}
+
+/// Queued animation step
+///
+/// Used with `RoboEyes::queue_animation` to script sequences. Each step
+/// starts once the previous one has finished.
+///
+/// - `Blink`: Close and reopen both eyes
+/// - `Wink(left)`: Blink only the left (`true`) or right (`false`) eye
+/// - `Confused`: Horizontal shake for the confused duration
+/// - `Laugh`: Vertical bounce for the laugh duration
+/// - `Shiver(ms)`: Small tremble for `ms` milliseconds
+/// - `Wait(ms)`: Do nothing for `ms` milliseconds
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anim {
+    Blink,
+    Wink(bool),
+    Confused,
+    Laugh,
+    Shiver(u64),
+    Wait(u64),
+}
+
+/// Kind of animation currently running
+///
+/// Reported by `RoboEyes::active_animations`.
+///
+/// - `Blink`: A blink or wink is closing or reopening the eyes
+/// - `Confused`, `Laugh`, `Shiver`, `Acknowledge`, `Startle`, `Wake`, `Peek`, `Wince`: The matching gesture
+/// - `Resize`: Eye heights are still easing toward their target (mood
+///   change, curiosity, breathing, squint)
+/// - `Queued`: Queued animations are waiting to start
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnimKind {
+    Blink,
+    Confused,
+    Laugh,
+    Shiver,
+    Acknowledge,
+    Startle,
+    Wake,
+    Peek,
+    Wince,
+    Resize,
+    Queued,
+}
+
 /// Configuration for eye geometry
 ///
 /// Contains the default and current sizes for eyes. All values are in pixels.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EyeGeometry {
     /// Default eye width in pixels
     pub width: u32,
@@ -65,6 +223,8 @@ pub struct EyeGeometry {
     pub height: u32,
     /// Border radius for rounded corners
     pub border_radius: u32,
+    /// Per-corner radii, overriding `border_radius` when set
+    pub corner_radii: Option<CornerRadii>,
 }
 
 impl EyeGeometry {
@@ -74,19 +234,62 @@ impl EyeGeometry {
             width,
             height,
             border_radius,
+            corner_radii: None,
+        }
+    }
+
+    /// Radii of the four corners
+    pub fn corners(&self) -> CornerRadii {
+        self.corner_radii
+            .unwrap_or(CornerRadii::uniform(self.border_radius))
+    }
+}
+
+/// Corner radii of an eye in pixels
+///
+/// Each radius is limited to half of the eye's width and height when drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CornerRadii {
+    pub top_left: u32,
+    pub top_right: u32,
+    pub bottom_left: u32,
+    pub bottom_right: u32,
+}
+
+impl CornerRadii {
+    /// Same radius on all four corners
+    pub fn uniform(radius: u32) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_left: radius,
+            bottom_right: radius,
         }
     }
 }
 
+/// Which eye a setting applies to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    Left,
+    Right,
+}
+
 /// Configuration for blink behavior
 ///
 /// Controls automatic eye blinking:
 /// - `interval`: Base time between blinks in seconds
 /// - `variation`: Random variation added to interval (0 = no variation)
-#[derive(Debug, Clone)]
+/// - `double_blink_chance`: Probability (0.0-1.0) of a quick second blink
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlinkConfig {
     pub interval: u64,
     pub variation: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub double_blink_chance: f32,
 }
 
 impl Default for BlinkConfig {
@@ -94,6 +297,7 @@ impl Default for BlinkConfig {
         Self {
             interval: 1,
             variation: 4,
+            double_blink_chance: 0.0,
         }
     }
 }
@@ -105,7 +309,8 @@ impl Default for BlinkConfig {
 /// - `variation`: Random variation added to interval
 /// - `x_range`: X-axis movement range as percentage of available width (0-100), default 100
 /// - `y_range`: Y-axis movement range as percentage of available height (0-100), default 100
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IdleConfig {
     pub interval: u64,
     pub variation: u64,
@@ -141,6 +346,7 @@ impl Default for IdleConfig {
 ///     .with_space_between(15);
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RoboEyesConfig {
     /// Default eye width in pixels
     pub eye_width: u32,
@@ -150,6 +356,9 @@ pub struct RoboEyesConfig {
     pub border_radius: u32,
     /// Space between eyes
     pub space_between: u32,
+    /// Start with the eyes fully open instead of animating them open
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub start_open: bool,
 }
 
 impl Default for RoboEyesConfig {
@@ -159,6 +368,7 @@ impl Default for RoboEyesConfig {
             eye_height: 36,
             border_radius: 8,
             space_between: 10,
+            start_open: false,
         }
     }
 }
@@ -192,6 +402,89 @@ impl RoboEyesConfig {
         self.space_between = space;
         self
     }
+
+    /// Show fully open eyes on the very first frame
+    ///
+    /// By default the eyes start shut and the first frames animate them
+    /// open.
+    pub fn with_start_open(mut self, open: bool) -> Self {
+        self.start_open = open;
+        self
+    }
+}
+
+/// Snapshot of the user-facing RoboEyes settings
+///
+/// Captures mood, geometry and enabled modes so an eye "personality" can be
+/// stored (e.g. in a config file with the `serde` feature) and restored later
+/// with `RoboEyes::apply_settings`. Animation state and timers are not included.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoboEyesSettings {
+    /// Current mood
+    pub mood: Mood,
+    /// Eye width in pixels
+    pub eye_width: u32,
+    /// Eye height in pixels
+    pub eye_height: u32,
+    /// Left eye border radius
+    pub border_radius_left: u32,
+    /// Right eye border radius
+    pub border_radius_right: u32,
+    /// Space between eyes
+    pub space_between: u32,
+    /// Cyclops mode (single eye)
+    pub cyclops: bool,
+    /// Curious mode
+    pub curious: bool,
+    /// Sweat animation
+    pub sweat: bool,
+    /// Automatic blinking
+    pub autoblinker: bool,
+    /// Automatic blinking timing
+    pub blink: BlinkConfig,
+    /// Idle mode
+    pub idle: bool,
+    /// Idle mode timing and range
+    pub idle_config: IdleConfig,
+    /// Horizontal flicker enabled
+    pub h_flicker: bool,
+    /// Horizontal flicker amplitude
+    pub h_flicker_amplitude: u32,
+    /// Vertical flicker enabled
+    pub v_flicker: bool,
+    /// Vertical flicker amplitude
+    pub v_flicker_amplitude: u32,
+    /// Foreground (eye) color
+    pub fg_color: u8,
+    /// Background color
+    pub bg_color: u8,
+}
+
+/// Pixel rectangle occupied by an eye
+///
+/// `x`/`y` is the top-left corner in screen coordinates (may be negative
+/// while an eye is partly off screen), `w`/`h` the size in pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub w: u32,
+    pub h: u32,
+}
+
+/// Drawing work done for the last frame
+///
+/// Returned by `RoboEyes::stats` (requires the `stats` feature).
+/// `pixels_written` counts every pixel written by a shape (overlapping
+/// shapes count twice) but not the background clear.
+#[cfg(feature = "stats")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DrawStats {
+    pub pixels_written: u64,
+    pub rects_drawn: u32,
+    pub triangles_drawn: u32,
 }
 
 /// Screen constraint calculation helper
@@ -215,4 +508,22 @@ impl ScreenConstraints {
     pub fn max_y(&self, eye_height: u32) -> i32 {
         (self.height as i32) - eye_height as i32
     }
+
+    /// X position for the left eye that centres the pair
+    ///
+    /// Rounds to nearest, so an odd leftover width puts the extra pixel
+    /// on the left instead of always biasing the eyes left.
+    pub fn center_x(&self, eye_width: u32, space_between: u32, right_eye_width: u32) -> i32 {
+        half_rounded(self.max_x(eye_width, space_between, right_eye_width))
+    }
+
+    /// Y position for the left eye that centres it vertically, rounding to nearest
+    pub fn center_y(&self, eye_height: u32) -> i32 {
+        half_rounded(self.max_y(eye_height))
+    }
+}
+
+/// Half of `value`, rounded to nearest with halves rounding up
+pub(crate) fn half_rounded(value: i32) -> i32 {
+    value.div_euclid(2) + value.rem_euclid(2)
 }